@@ -6,9 +6,24 @@
 use base64::Engine;
 use console_error_panic_hook::set_once as set_panic_hook;
 use type_description::TypeDescription;
-use wasm_bindgen::{prelude::Closure, JsCast};
+use wasm_bindgen::{
+    prelude::{wasm_bindgen, Closure},
+    JsCast,
+};
 use web_sys::{window, Event, HtmlDivElement, HtmlTextAreaElement};
 
+/// Render a [`TypeDescription`] serialized as JSON to an HTML fragment
+#[wasm_bindgen]
+pub fn render_html(json: &str) -> String {
+    match serde_json::from_str::<TypeDescription>(json) {
+        Ok(desc) => type_description::render::to_html(&desc),
+        Err(error) => format!(
+            "<p class=\"error\">Could not parse the description: {}</p>",
+            error.to_string().replace('&', "&amp;").replace('<', "&lt;")
+        ),
+    }
+}
+
 fn start_app() {
     let document = window()
         .and_then(|win| win.document())
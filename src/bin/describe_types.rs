@@ -4,15 +4,23 @@
 //   file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
-use std::{error::Error, fmt::Display};
+use std::{error::Error, fmt::Display, path::PathBuf};
 
-use clap::{Parser, ValueEnum};
-use type_description::{render::render_to_markdown, render::render_to_terminal, TypeDescription};
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand, ValueEnum};
+use type_description::{
+    render::render_plain, render::render_signature, render::render_to_json_schema,
+    render::render_to_markdown, render::render_to_terminal_string, render::to_html,
+    TypeDescription,
+};
 
 #[derive(Debug, ValueEnum, PartialEq, Clone, Copy)]
 enum OutputFormat {
     Markdown,
     Terminal,
+    Plain,
+    JsonSchema,
+    Html,
+    Signature,
 }
 
 impl Display for OutputFormat {
@@ -20,37 +28,96 @@ impl Display for OutputFormat {
         match self {
             OutputFormat::Markdown => write!(f, "markdown"),
             OutputFormat::Terminal => write!(f, "terminal"),
+            OutputFormat::Plain => write!(f, "plain"),
+            OutputFormat::JsonSchema => write!(f, "json-schema"),
+            OutputFormat::Html => write!(f, "html"),
+            OutputFormat::Signature => write!(f, "signature"),
         }
     }
 }
 
 #[derive(Parser, Debug)]
-#[clap(author, version, about)]
+#[clap(
+    author,
+    version,
+    about,
+    bin_name = "describe_types",
+    args_conflicts_with_subcommands = true
+)]
 struct Args {
-    #[clap(short, long, value_parser)]
-    output_format: OutputFormat,
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    /// Deprecated, use `describe --format` instead
+    ///
+    /// Renders the description read from stdin in the given format.
+    #[clap(short, long, value_parser, hide = true)]
+    output_format: Option<OutputFormat>,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args = Args::parse();
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Render a TypeDescription serialized as JSON
+    Describe {
+        /// The format to render the description in
+        #[clap(short, long, value_parser, default_value_t = OutputFormat::Terminal)]
+        format: OutputFormat,
+
+        /// The file to read the description from, stdin if not given
+        input: Option<PathBuf>,
+    },
+}
 
-    let input: TypeDescription = serde_json::from_reader(std::io::stdin())?;
+fn describe(format: OutputFormat, input: Option<PathBuf>) -> Result<(), Box<dyn Error>> {
+    let input: TypeDescription = match input {
+        Some(path) => serde_json::from_reader(std::fs::File::open(path)?)?,
+        None => serde_json::from_reader(std::io::stdin())?,
+    };
 
-    match args.output_format {
+    match format {
         OutputFormat::Markdown => {
             println!("{}", render_to_markdown(&input)?);
         }
         OutputFormat::Terminal => {
             let terminal_width = term_size::dimensions().map(|(w, _)| w).unwrap_or(80);
-            let arena = pretty::Arena::new();
-
-            let rendered_doc = render_to_terminal(&input, &arena);
-
-            let mut output = String::new();
-            rendered_doc.render_fmt(terminal_width, &mut output)?;
-            println!("{}", output);
+            println!("{}", render_to_terminal_string(&input, terminal_width)?);
+        }
+        OutputFormat::Plain => {
+            print!("{}", render_plain(&input));
+        }
+        OutputFormat::JsonSchema => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&render_to_json_schema(&input))?
+            );
+        }
+        OutputFormat::Html => {
+            println!("{}", to_html(&input));
+        }
+        OutputFormat::Signature => {
+            println!("{}", render_signature(&input));
         }
     }
 
     Ok(())
 }
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    match (args.command, args.output_format) {
+        (Some(Command::Describe { format, input }), _) => describe(format, input),
+        (None, Some(format)) => {
+            eprintln!(
+                "warning: `--output-format` is deprecated, use `describe --format {format}` instead"
+            );
+            describe(format, None)
+        }
+        (None, None) => Args::command()
+            .error(
+                ErrorKind::MissingSubcommand,
+                "a subcommand is required, see `--help`",
+            )
+            .exit(),
+    }
+}
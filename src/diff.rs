@@ -0,0 +1,480 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    join_path, Constraint, EnumVariant, EnumVariantRepresentation, StructField, TypeDescription,
+    TypeEnumKind, TypeKind,
+};
+
+/// A single difference between two [`struct@TypeDescription`]s
+///
+/// Every change carries the path to where it happened, with struct fields and enum variants
+/// separated by `.`, array elements as `[]`, map keys as `{key}` and map values as `{}`. The
+/// root has the empty path.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub enum TypeChange {
+    /// The name of the type changed
+    NameChanged {
+        /// Where the change happened
+        path: String,
+        /// The previous name
+        old: String,
+        /// The new name
+        new: String,
+    },
+    /// The documentation changed
+    DocChanged {
+        /// Where the change happened
+        path: String,
+        /// The previous documentation
+        old: Option<String>,
+        /// The new documentation
+        new: Option<String>,
+    },
+    /// The constraints on the values changed
+    ConstraintsChanged {
+        /// Where the change happened
+        path: String,
+        /// The previous constraints
+        old: Vec<Constraint>,
+        /// The new constraints
+        new: Vec<Constraint>,
+    },
+    /// The type has been replaced by a different kind of type
+    KindChanged {
+        /// Where the change happened
+        path: String,
+        /// The previous type
+        old: TypeDescription,
+        /// The new type
+        new: TypeDescription,
+    },
+    /// A struct field was added
+    FieldAdded {
+        /// The path of the struct
+        path: String,
+        /// The added field
+        field: StructField,
+    },
+    /// A struct field was removed
+    FieldRemoved {
+        /// The path of the struct
+        path: String,
+        /// The removed field
+        field: StructField,
+    },
+    /// A struct field was renamed, while keeping its type
+    FieldRenamed {
+        /// The path of the struct
+        path: String,
+        /// The previous name of the field
+        old: String,
+        /// The new name of the field
+        new: String,
+    },
+    /// A struct field became optional or required
+    OptionalityChanged {
+        /// Where the change happened
+        path: String,
+        /// Whether the field is now optional
+        optional: bool,
+    },
+    /// The tagging of an enum changed
+    TaggingChanged {
+        /// Where the change happened
+        path: String,
+        /// The previous tagging
+        old: TypeEnumKind,
+        /// The new tagging
+        new: TypeEnumKind,
+    },
+    /// An enum variant was added
+    VariantAdded {
+        /// The path of the enum
+        path: String,
+        /// The added variant
+        variant: EnumVariant,
+    },
+    /// An enum variant was removed
+    VariantRemoved {
+        /// The path of the enum
+        path: String,
+        /// The removed variant
+        variant: EnumVariant,
+    },
+    /// The representation of an enum variant changed
+    RepresentationChanged {
+        /// Where the change happened
+        path: String,
+        /// The previous representation
+        old: EnumVariantRepresentation,
+        /// The new representation
+        new: EnumVariantRepresentation,
+    },
+}
+
+fn diff_docs(path: &str, old: Option<&str>, new: Option<&str>, changes: &mut Vec<TypeChange>) {
+    if old != new {
+        changes.push(TypeChange::DocChanged {
+            path: path.to_string(),
+            old: old.map(String::from),
+            new: new.map(String::from),
+        });
+    }
+}
+
+fn diff_fields(
+    path: &str,
+    old: &[StructField],
+    new: &[StructField],
+    changes: &mut Vec<TypeChange>,
+) {
+    let mut removed = old
+        .iter()
+        .filter(|field| !new.iter().any(|other| other.name() == field.name()))
+        .collect::<Vec<_>>();
+    let mut added = new
+        .iter()
+        .filter(|field| !old.iter().any(|other| other.name() == field.name()))
+        .collect::<Vec<_>>();
+
+    for old_field in old {
+        if let Some(new_field) = new.iter().find(|field| field.name() == old_field.name()) {
+            let field_path = join_path(path, old_field.name());
+            diff_docs(&field_path, old_field.doc(), new_field.doc(), changes);
+            if old_field.optional() != new_field.optional() {
+                changes.push(TypeChange::OptionalityChanged {
+                    path: field_path.clone(),
+                    optional: new_field.optional(),
+                });
+            }
+            diff_into(&field_path, old_field.kind(), new_field.kind(), changes);
+        }
+    }
+
+    // A field that disappeared while another one of the same type appeared is a rename
+    removed.retain(|old_field| {
+        match added
+            .iter()
+            .position(|new_field| new_field.kind() == old_field.kind())
+        {
+            Some(idx) => {
+                let new_field = added.remove(idx);
+                changes.push(TypeChange::FieldRenamed {
+                    path: path.to_string(),
+                    old: old_field.name().to_string(),
+                    new: new_field.name().to_string(),
+                });
+                false
+            }
+            None => true,
+        }
+    });
+
+    changes.extend(removed.into_iter().map(|field| TypeChange::FieldRemoved {
+        path: path.to_string(),
+        field: field.clone(),
+    }));
+    changes.extend(added.into_iter().map(|field| TypeChange::FieldAdded {
+        path: path.to_string(),
+        field: field.clone(),
+    }));
+}
+
+fn diff_variants(
+    path: &str,
+    old: &[EnumVariant],
+    new: &[EnumVariant],
+    changes: &mut Vec<TypeChange>,
+) {
+    for old_variant in old {
+        match new
+            .iter()
+            .find(|variant| variant.name() == old_variant.name())
+        {
+            Some(new_variant) => {
+                let variant_path = join_path(path, old_variant.name());
+                diff_docs(&variant_path, old_variant.doc(), new_variant.doc(), changes);
+                match (old_variant.repr(), new_variant.repr()) {
+                    (
+                        EnumVariantRepresentation::Wrapped(old_inner),
+                        EnumVariantRepresentation::Wrapped(new_inner),
+                    ) => diff_into(&variant_path, old_inner, new_inner, changes),
+                    (old_repr, new_repr) if old_repr != new_repr => {
+                        changes.push(TypeChange::RepresentationChanged {
+                            path: variant_path,
+                            old: old_repr.clone(),
+                            new: new_repr.clone(),
+                        })
+                    }
+                    _ => (),
+                }
+            }
+            None => changes.push(TypeChange::VariantRemoved {
+                path: path.to_string(),
+                variant: old_variant.clone(),
+            }),
+        }
+    }
+
+    changes.extend(
+        new.iter()
+            .filter(|variant| !old.iter().any(|other| other.name() == variant.name()))
+            .map(|variant| TypeChange::VariantAdded {
+                path: path.to_string(),
+                variant: variant.clone(),
+            }),
+    );
+}
+
+fn diff_into(
+    path: &str,
+    old: &TypeDescription,
+    new: &TypeDescription,
+    changes: &mut Vec<TypeChange>,
+) {
+    if old.name() != new.name() {
+        changes.push(TypeChange::NameChanged {
+            path: path.to_string(),
+            old: old.name().to_string(),
+            new: new.name().to_string(),
+        });
+    }
+    diff_docs(path, old.doc(), new.doc(), changes);
+    if old.constraints() != new.constraints() {
+        changes.push(TypeChange::ConstraintsChanged {
+            path: path.to_string(),
+            old: old.constraints().to_vec(),
+            new: new.constraints().to_vec(),
+        });
+    }
+
+    match (old.kind(), new.kind()) {
+        (TypeKind::Wrapped(old_inner), TypeKind::Wrapped(new_inner)) => {
+            diff_into(path, old_inner, new_inner, changes)
+        }
+        (TypeKind::Array(old_inner), TypeKind::Array(new_inner)) => {
+            diff_into(&format!("{path}[]"), old_inner, new_inner, changes)
+        }
+        (TypeKind::Tuple(old_elements), TypeKind::Tuple(new_elements))
+            if old_elements.len() == new_elements.len() =>
+        {
+            for (idx, (old_element, new_element)) in
+                old_elements.iter().zip(new_elements).enumerate()
+            {
+                diff_into(
+                    &join_path(path, &idx.to_string()),
+                    old_element,
+                    new_element,
+                    changes,
+                );
+            }
+        }
+        (TypeKind::OneOf(old_choices), TypeKind::OneOf(new_choices))
+            if old_choices.len() == new_choices.len() =>
+        {
+            for (idx, (old_choice, new_choice)) in old_choices.iter().zip(new_choices).enumerate() {
+                diff_into(
+                    &join_path(path, &idx.to_string()),
+                    old_choice,
+                    new_choice,
+                    changes,
+                );
+            }
+        }
+        (
+            TypeKind::HashMap {
+                key: old_key,
+                value: old_value,
+            },
+            TypeKind::HashMap {
+                key: new_key,
+                value: new_value,
+            },
+        ) => {
+            diff_into(&format!("{path}{{key}}"), old_key, new_key, changes);
+            diff_into(&format!("{path}{{}}"), old_value, new_value, changes);
+        }
+        (TypeKind::Struct(old_fields), TypeKind::Struct(new_fields)) => {
+            diff_fields(path, old_fields, new_fields, changes)
+        }
+        (
+            TypeKind::Enum {
+                tagging: old_tag,
+                variants: old_variants,
+                ..
+            },
+            TypeKind::Enum {
+                tagging: new_tag,
+                variants: new_variants,
+                ..
+            },
+        ) => {
+            if old_tag != new_tag {
+                changes.push(TypeChange::TaggingChanged {
+                    path: path.to_string(),
+                    old: old_tag.clone(),
+                    new: new_tag.clone(),
+                });
+            }
+            diff_variants(path, old_variants, new_variants, changes)
+        }
+        (old_kind, new_kind) if old_kind != new_kind => changes.push(TypeChange::KindChanged {
+            path: path.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => (),
+    }
+}
+
+/// Compute the changes needed to get from `old` to `new`
+///
+/// This recurses through the whole tree, so that e.g. a changed field type deep inside a nested
+/// struct is reported with its full path.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::diff::{diff, TypeChange};
+///
+///     let changes = diff(&Vec::<u8>::as_type_description(), &Vec::<u8>::as_type_description());
+///     assert!(changes.is_empty());
+/// ```
+pub fn diff(old: &TypeDescription, new: &TypeDescription) -> Vec<TypeChange> {
+    let mut changes = vec![];
+    diff_into("", old, new, &mut changes);
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, StructField, TypeDescription, TypeKind};
+
+    use super::{diff, TypeChange};
+
+    fn server(fields: Vec<StructField>) -> TypeDescription {
+        TypeDescription::new("Server".into(), TypeKind::Struct(fields), None)
+    }
+
+    #[test]
+    fn diff_changed_field_type() {
+        let old = server(vec![StructField::new(
+            "port",
+            None,
+            u16::as_type_description(),
+            false,
+        )]);
+        let new = server(vec![StructField::new(
+            "port",
+            None,
+            u32::as_type_description(),
+            false,
+        )]);
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                TypeChange::DocChanged {
+                    path: "port".into(),
+                    old: Some("An unsigned integer with 16 bits".into()),
+                    new: Some("An unsigned integer with 32 bits".into()),
+                },
+                TypeChange::KindChanged {
+                    path: "port".into(),
+                    old: u16::as_type_description(),
+                    new: u32::as_type_description(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_added_and_renamed_fields() {
+        let old = server(vec![
+            StructField::new("host", None, String::as_type_description(), false),
+            StructField::new("hosts", None, Vec::<String>::as_type_description(), false),
+        ]);
+        let timeout = StructField::new("timeout", None, u64::as_type_description(), true);
+        let new = server(vec![
+            StructField::new("hostname", None, String::as_type_description(), false),
+            StructField::new("hosts", None, Vec::<String>::as_type_description(), false),
+            timeout.clone(),
+        ]);
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                TypeChange::FieldRenamed {
+                    path: "".into(),
+                    old: "host".into(),
+                    new: "hostname".into(),
+                },
+                TypeChange::FieldAdded {
+                    path: "".into(),
+                    field: timeout,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_nested_paths() {
+        let servers = |fields| {
+            TypeDescription::new(
+                "Servers".into(),
+                TypeKind::Array(Box::new(server(fields))),
+                None,
+            )
+        };
+        let port = StructField::new("port", None, u16::as_type_description(), false);
+
+        assert_eq!(
+            diff(&servers(vec![port.clone()]), &servers(vec![])),
+            vec![TypeChange::FieldRemoved {
+                path: "[]".into(),
+                field: port,
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_any() {
+        let any = TypeDescription::from(TypeKind::Any);
+        let port = StructField::new("port", None, u16::as_type_description(), false);
+        let payload = |kind| server(vec![StructField::new("payload", None, kind, false)]);
+
+        assert!(diff(&payload(any.clone()), &payload(any.clone())).is_empty());
+        assert_eq!(
+            diff(&payload(any.clone()), &payload(server(vec![port]))),
+            vec![
+                TypeChange::NameChanged {
+                    path: "payload".into(),
+                    old: "Any".into(),
+                    new: "Server".into(),
+                },
+                TypeChange::DocChanged {
+                    path: "payload".into(),
+                    old: Some("Any value".into()),
+                    new: None,
+                },
+                TypeChange::KindChanged {
+                    path: "payload".into(),
+                    old: any,
+                    new: server(vec![StructField::new(
+                        "port",
+                        None,
+                        u16::as_type_description(),
+                        false,
+                    )]),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,441 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use crate::{EnumVariantRepresentation, TypeDescription, TypeEnumKind, TypeKind};
+
+/// The reasons no example can be generated for a [`struct@TypeDescription`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum ExampleError {
+    /// The format needs a table at the top level, but the type with this name is something else
+    NotATable(String),
+}
+
+impl std::fmt::Display for ExampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExampleError::NotATable(name) => {
+                write!(f, "'{name}' cannot be written as a table at the top level")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExampleError {}
+
+/// A placeholder value in an example configuration
+enum Node {
+    /// A value written inline, already in a syntax that YAML and TOML share
+    Scalar(String),
+    /// No value at all, which TOML cannot express
+    Null,
+    /// A table with nested keys
+    Table(Vec<Entry>),
+    /// An array of tables, shown with a single example element
+    TableArray(Vec<Entry>),
+}
+
+/// A key in a table, with the comments written above it
+struct Entry {
+    comments: Vec<String>,
+    key: String,
+    value: Node,
+}
+
+fn comment_lines(doc: Option<&str>) -> Vec<String> {
+    doc.map(|doc| doc.lines().map(String::from).collect())
+        .unwrap_or_default()
+}
+
+fn unwrap(desc: &TypeDescription) -> &TypeDescription {
+    match desc.kind() {
+        TypeKind::Wrapped(inner) => unwrap(inner),
+        _ => desc,
+    }
+}
+
+/// Quote a string with the escapes that YAML and TOML double quoted strings have in common
+fn quote(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn skeleton(desc: &TypeDescription) -> Node {
+    match unwrap(desc).kind() {
+        TypeKind::Bool => Node::Scalar("false".into()),
+        TypeKind::Integer { .. } => Node::Scalar("0".into()),
+        TypeKind::Float { .. } => Node::Scalar("0.0".into()),
+        TypeKind::String | TypeKind::Char | TypeKind::Bytes => Node::Scalar(quote("")),
+        TypeKind::Unit | TypeKind::Any => Node::Null,
+        TypeKind::Wrapped(_) => unreachable!("Wrapped types are unwrapped above"),
+        TypeKind::Array(inner) => match skeleton(inner) {
+            Node::Table(entries) => Node::TableArray(entries),
+            _ => Node::Scalar("[]".into()),
+        },
+        TypeKind::Tuple(elements) => Node::Scalar(format!(
+            "[{}]",
+            elements
+                .iter()
+                .map(|element| match skeleton(element) {
+                    Node::Scalar(value) => value,
+                    Node::Null => "null".into(),
+                    Node::Table(_) | Node::TableArray(_) => "{}".into(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        TypeKind::HashMap { .. } | TypeKind::Ref(_) => Node::Scalar("{}".into()),
+        TypeKind::Struct(fields) => Node::Table(
+            fields
+                .iter()
+                .map(|field| {
+                    let mut comments = comment_lines(field.doc().or_else(|| field.kind().doc()));
+                    if field.optional() {
+                        comments.push("Optional".into());
+                    }
+                    if let TypeKind::Enum { variants, .. } = unwrap(field.kind()).kind() {
+                        comments.push(format!(
+                            "One of: {}",
+                            variants
+                                .iter()
+                                .map(|variant| variant.name())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ));
+                    }
+                    Entry {
+                        comments,
+                        key: field.name().to_string(),
+                        value: skeleton(field.kind()),
+                    }
+                })
+                .collect(),
+        ),
+        TypeKind::Enum {
+            tagging, variants, ..
+        } => {
+            let Some(variant) = variants.first() else {
+                return Node::Scalar("{}".into());
+            };
+            match (tagging, variant.repr()) {
+                (TypeEnumKind::Tagged(tag), repr) => {
+                    let mut entries = vec![Entry {
+                        comments: vec![],
+                        key: tag.to_string(),
+                        value: Node::Scalar(quote(variant.name())),
+                    }];
+                    if let EnumVariantRepresentation::Wrapped(inner) = repr {
+                        if let Node::Table(fields) = skeleton(inner) {
+                            entries.extend(fields);
+                        }
+                    }
+                    Node::Table(entries)
+                }
+                (TypeEnumKind::Adjacent { tag, content }, repr) => {
+                    let mut entries = vec![Entry {
+                        comments: vec![],
+                        key: tag.to_string(),
+                        value: Node::Scalar(quote(variant.name())),
+                    }];
+                    if let EnumVariantRepresentation::Wrapped(inner) = repr {
+                        entries.push(Entry {
+                            comments: comment_lines(variant.doc()),
+                            key: content.to_string(),
+                            value: skeleton(inner),
+                        });
+                    }
+                    Node::Table(entries)
+                }
+                (_, EnumVariantRepresentation::String(repr)) => Node::Scalar(quote(repr)),
+                (TypeEnumKind::Untagged, EnumVariantRepresentation::Wrapped(inner)) => {
+                    skeleton(inner)
+                }
+                (TypeEnumKind::External, EnumVariantRepresentation::Wrapped(inner)) => {
+                    Node::Table(vec![Entry {
+                        comments: comment_lines(variant.doc()),
+                        key: variant.name().to_string(),
+                        value: skeleton(inner),
+                    }])
+                }
+            }
+        }
+        TypeKind::OneOf(choices) => choices
+            .first()
+            .map(skeleton)
+            .unwrap_or_else(|| Node::Scalar("{}".into())),
+    }
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn key(key: &str) -> String {
+    if is_bare_key(key) {
+        key.to_string()
+    } else {
+        quote(key)
+    }
+}
+
+fn write_comments(comments: &[String], indent: usize, out: &mut String) {
+    for comment in comments {
+        out.push_str(&format!("{:indent$}# {comment}\n", ""));
+    }
+}
+
+#[cfg(feature = "yaml")]
+fn write_yaml_entries(entries: &[Entry], indent: usize, out: &mut String) {
+    for entry in entries {
+        write_comments(&entry.comments, indent, out);
+        let key = key(&entry.key);
+        match &entry.value {
+            Node::Scalar(value) => out.push_str(&format!("{:indent$}{key}: {value}\n", "")),
+            Node::Null => out.push_str(&format!("{:indent$}{key}: null\n", "")),
+            Node::Table(entries) if entries.is_empty() => {
+                out.push_str(&format!("{:indent$}{key}: {{}}\n", ""))
+            }
+            Node::Table(entries) => {
+                out.push_str(&format!("{:indent$}{key}:\n", ""));
+                write_yaml_entries(entries, indent + 2, out);
+            }
+            Node::TableArray(entries) => {
+                out.push_str(&format!("{:indent$}{key}:\n{:indent$}  -\n", "", ""));
+                write_yaml_entries(entries, indent + 4, out);
+            }
+        }
+    }
+}
+
+/// Generate a commented YAML skeleton for the given description
+///
+/// Every key gets a placeholder value of the right kind, e.g. `0` for integers, `""` for strings
+/// and nested keys for structs, with its documentation as `#` comments above it. Enums are shown
+/// with their first variant. This is meant as a starting point for a configuration file, the
+/// placeholders are not necessarily valid values.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, StructBuilder};
+///     use type_description::example::example_yaml;
+///
+///     let desc = StructBuilder::new("Server".into(), None)
+///         .field("port", Some("The port to listen on"), u16::as_type_description())
+///         .build();
+///
+///     assert_eq!(example_yaml(&desc), "# The port to listen on\nport: 0\n");
+/// ```
+#[cfg(feature = "yaml")]
+pub fn example_yaml(desc: &TypeDescription) -> String {
+    let mut out = String::new();
+    write_comments(&comment_lines(desc.doc()), 0, &mut out);
+
+    match skeleton(desc) {
+        Node::Scalar(value) => out.push_str(&format!("{value}\n")),
+        Node::Null => out.push_str("null\n"),
+        Node::Table(entries) if entries.is_empty() => out.push_str("{}\n"),
+        Node::Table(entries) => write_yaml_entries(&entries, 0, &mut out),
+        Node::TableArray(entries) => {
+            out.push_str("-\n");
+            write_yaml_entries(&entries, 2, &mut out);
+        }
+    }
+
+    out
+}
+
+#[cfg(feature = "toml")]
+fn write_toml_table(path: &[String], entries: &[Entry], out: &mut String) {
+    // Plain keys have to come before any sub-table, as they would belong to it otherwise. Keys
+    // without a value are left out, as they are in serialized TOML.
+    for entry in entries {
+        let value = match &entry.value {
+            Node::Scalar(value) => value.as_str(),
+            Node::Table(entries) if entries.is_empty() => "{}",
+            Node::Null | Node::Table(_) | Node::TableArray(_) => continue,
+        };
+        write_comments(&entry.comments, 0, out);
+        out.push_str(&format!("{} = {value}\n", key(&entry.key)));
+    }
+
+    for entry in entries {
+        let (header, entries) = match &entry.value {
+            Node::Table(entries) if !entries.is_empty() => (("[", "]"), entries),
+            Node::TableArray(entries) => (("[[", "]]"), entries),
+            Node::Scalar(_) | Node::Null | Node::Table(_) => continue,
+        };
+        let mut path = path.to_vec();
+        path.push(key(&entry.key));
+
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        write_comments(&entry.comments, 0, out);
+        out.push_str(&format!("{}{}{}\n", header.0, path.join("."), header.1));
+        write_toml_table(&path, entries, out);
+    }
+}
+
+/// Generate a commented TOML template for the given description
+///
+/// This works like [`example_yaml`](fn.example_yaml.html), with nested structs written as
+/// `[section]` tables and arrays of structs as `[[array]]` tables with a single example element.
+/// As a TOML document is always a table, this fails for anything that is not written as one,
+/// e.g. a plain integer.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, StructBuilder};
+///     use type_description::example::{example_toml, ExampleError};
+///
+///     let desc = StructBuilder::new("Server".into(), None)
+///         .field("port", Some("The port to listen on"), u16::as_type_description())
+///         .build();
+///
+///     assert_eq!(example_toml(&desc).unwrap(), "# The port to listen on\nport = 0\n");
+///     assert_eq!(
+///         example_toml(&u16::as_type_description()),
+///         Err(ExampleError::NotATable("Integer".into()))
+///     );
+/// ```
+#[cfg(feature = "toml")]
+pub fn example_toml(desc: &TypeDescription) -> Result<String, ExampleError> {
+    let entries = match skeleton(desc) {
+        Node::Table(entries) => entries,
+        Node::Scalar(_) | Node::Null | Node::TableArray(_) => {
+            return Err(ExampleError::NotATable(desc.name().to_string()))
+        }
+    };
+
+    let mut out = String::new();
+    write_comments(&comment_lines(desc.doc()), 0, &mut out);
+    write_toml_table(&[], &entries, &mut out);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, StructBuilder, TypeDescription, TypeKind};
+
+    fn server() -> TypeDescription {
+        let tls = StructBuilder::new("Tls".into(), None)
+            .field(
+                "cert",
+                Some("Path to the certificate"),
+                String::as_type_description(),
+            )
+            .build();
+        let user = StructBuilder::new("User".into(), None)
+            .field(
+                "name",
+                Some("The login name"),
+                String::as_type_description(),
+            )
+            .field(
+                "admin",
+                Some("Whether the user may change settings"),
+                bool::as_type_description(),
+            )
+            .build();
+        StructBuilder::new("Server".into(), Some("The server configuration"))
+            .field(
+                "port",
+                Some("The port to listen on"),
+                u16::as_type_description(),
+            )
+            .optional_field(
+                "timeout",
+                Some("Seconds until idle connections are closed"),
+                f64::as_type_description(),
+            )
+            .field("tls", None, tls)
+            .field(
+                "users",
+                None,
+                TypeDescription::from(TypeKind::Array(Box::new(user))),
+            )
+            .field(
+                "tags",
+                Some("Free form tags"),
+                Vec::<String>::as_type_description(),
+            )
+            .build()
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_skeleton() {
+        use super::example_yaml;
+
+        assert_eq!(
+            example_yaml(&server()),
+            "\
+# The server configuration
+# The port to listen on
+port: 0
+# Seconds until idle connections are closed
+# Optional
+timeout: 0.0
+tls:
+  # Path to the certificate
+  cert: \"\"
+users:
+  -
+    # The login name
+    name: \"\"
+    # Whether the user may change settings
+    admin: false
+# Free form tags
+tags: []
+"
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_template() {
+        use super::example_toml;
+
+        assert_eq!(
+            example_toml(&server()).unwrap(),
+            "\
+# The server configuration
+# The port to listen on
+port = 0
+# Seconds until idle connections are closed
+# Optional
+timeout = 0.0
+# Free form tags
+tags = []
+
+[tls]
+# Path to the certificate
+cert = \"\"
+
+[[users]]
+# The login name
+name = \"\"
+# Whether the user may change settings
+admin = false
+"
+        );
+    }
+}
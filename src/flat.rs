@@ -0,0 +1,530 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Constraint, EnumVariant, EnumVariantRepresentation, Sign, StructField, TypeDescription,
+    TypeEnumKind, TypeKind,
+};
+
+/// The index of a [`Node`] in a [`FlatTypeDescription`]
+pub type NodeId = usize;
+
+/// A [`struct@TypeDescription`] stored as a list of nodes instead of a tree
+///
+/// Nodes refer to the types they contain by their [`NodeId`] instead of owning them. The nodes
+/// are stored in the order they are encountered when walking the tree depth first, which puts
+/// the root at index `0` and every node before the nodes it contains.
+///
+/// Deserializing checks that this holds, so that there always is a root and the nodes cannot
+/// refer to missing nodes or in a cycle.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(try_from = "UncheckedFlatTypeDescription")]
+pub struct FlatTypeDescription {
+    nodes: Vec<Node>,
+}
+
+/// A [`FlatTypeDescription`] as it is deserialized, before its nodes are checked
+#[derive(Deserialize)]
+struct UncheckedFlatTypeDescription {
+    nodes: Vec<Node>,
+}
+
+impl TryFrom<UncheckedFlatTypeDescription> for FlatTypeDescription {
+    type Error = String;
+
+    fn try_from(unchecked: UncheckedFlatTypeDescription) -> Result<Self, Self::Error> {
+        let nodes = unchecked.nodes;
+        if nodes.is_empty() {
+            return Err("A flat description needs at least one node".to_string());
+        }
+        for (id, node) in nodes.iter().enumerate() {
+            if let Some(child) = node
+                .kind
+                .children()
+                .into_iter()
+                .find(|child| *child <= id || *child >= nodes.len())
+            {
+                return Err(format!(
+                    "Node {id} refers to node {child}, which does not come after it"
+                ));
+            }
+        }
+        Ok(FlatTypeDescription { nodes })
+    }
+}
+
+impl FlatTypeDescription {
+    /// Get the node the description starts at
+    #[must_use]
+    pub fn root(&self) -> &Node {
+        // There is always at least the root, see the checks when deserializing
+        &self.nodes[0]
+    }
+
+    /// Get the node with the given id, if there is one
+    #[must_use]
+    pub fn node(&self, id: NodeId) -> Option<&Node> {
+        self.nodes.get(id)
+    }
+
+    /// Get all nodes, in depth first order
+    #[must_use]
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    fn push(&mut self, desc: &TypeDescription) -> NodeId {
+        let id = self.nodes.len();
+        // Reserve the place first, so that the node comes before the nodes it contains
+        self.nodes.push(Node {
+            name: desc.name.clone(),
+            kind: FlatKind::Unit,
+            doc: desc.doc.clone(),
+            constraints: desc.constraints.clone(),
+            examples: desc.examples.clone(),
+            optional: desc.optional,
+        });
+
+        let kind = match &desc.kind {
+            TypeKind::Bool => FlatKind::Bool,
+            TypeKind::Integer { sign, size } => FlatKind::Integer {
+                sign: sign.clone(),
+                size: *size,
+            },
+            TypeKind::Float { size } => FlatKind::Float { size: *size },
+            TypeKind::String => FlatKind::String,
+            TypeKind::Char => FlatKind::Char,
+            TypeKind::Bytes => FlatKind::Bytes,
+            TypeKind::Unit => FlatKind::Unit,
+            TypeKind::Any => FlatKind::Any,
+            TypeKind::Wrapped(inner) => FlatKind::Wrapped(self.push(inner)),
+            TypeKind::Array(inner) => FlatKind::Array(self.push(inner)),
+            TypeKind::Tuple(elements) => {
+                FlatKind::Tuple(elements.iter().map(|element| self.push(element)).collect())
+            }
+            TypeKind::HashMap { key, value } => FlatKind::HashMap {
+                key: self.push(key),
+                value: self.push(value),
+            },
+            TypeKind::Struct(fields) => FlatKind::Struct(
+                fields
+                    .iter()
+                    .map(|field| FlatField {
+                        name: field.name.clone(),
+                        label: field.label.clone(),
+                        doc: field.doc.clone(),
+                        kind: self.push(&field.kind),
+                        optional: field.optional,
+                    })
+                    .collect(),
+            ),
+            TypeKind::Enum {
+                tagging,
+                variants,
+                non_exhaustive,
+            } => FlatKind::Enum {
+                tagging: tagging.clone(),
+                variants: variants
+                    .iter()
+                    .map(|variant| FlatVariant {
+                        name: variant.name.clone(),
+                        label: variant.label.clone(),
+                        doc: variant.doc.clone(),
+                        repr: match &variant.repr {
+                            EnumVariantRepresentation::String(repr) => {
+                                FlatVariantRepresentation::String(repr.clone())
+                            }
+                            EnumVariantRepresentation::Wrapped(inner) => {
+                                FlatVariantRepresentation::Wrapped(self.push(inner))
+                            }
+                        },
+                    })
+                    .collect(),
+                non_exhaustive: *non_exhaustive,
+            },
+            TypeKind::OneOf(choices) => {
+                FlatKind::OneOf(choices.iter().map(|choice| self.push(choice)).collect())
+            }
+            TypeKind::Ref(name) => FlatKind::Ref(name.clone()),
+        };
+        self.nodes[id].kind = kind;
+        id
+    }
+
+    fn build(&self, id: NodeId) -> TypeDescription {
+        let node = &self.nodes[id];
+        let boxed = |id: NodeId| Box::new(self.build(id));
+        let kind = match &node.kind {
+            FlatKind::Bool => TypeKind::Bool,
+            FlatKind::Integer { sign, size } => TypeKind::Integer {
+                sign: sign.clone(),
+                size: *size,
+            },
+            FlatKind::Float { size } => TypeKind::Float { size: *size },
+            FlatKind::String => TypeKind::String,
+            FlatKind::Char => TypeKind::Char,
+            FlatKind::Bytes => TypeKind::Bytes,
+            FlatKind::Unit => TypeKind::Unit,
+            FlatKind::Any => TypeKind::Any,
+            FlatKind::Wrapped(inner) => TypeKind::Wrapped(boxed(*inner)),
+            FlatKind::Array(inner) => TypeKind::Array(boxed(*inner)),
+            FlatKind::Tuple(elements) => {
+                TypeKind::Tuple(elements.iter().map(|id| self.build(*id)).collect())
+            }
+            FlatKind::HashMap { key, value } => TypeKind::HashMap {
+                key: boxed(*key),
+                value: boxed(*value),
+            },
+            FlatKind::Struct(fields) => TypeKind::Struct(
+                fields
+                    .iter()
+                    .map(|field| StructField {
+                        name: field.name.clone(),
+                        label: field.label.clone(),
+                        doc: field.doc.clone(),
+                        kind: self.build(field.kind),
+                        optional: field.optional,
+                    })
+                    .collect(),
+            ),
+            FlatKind::Enum {
+                tagging,
+                variants,
+                non_exhaustive,
+            } => TypeKind::Enum {
+                tagging: tagging.clone(),
+                variants: variants
+                    .iter()
+                    .map(|variant| EnumVariant {
+                        name: variant.name.clone(),
+                        label: variant.label.clone(),
+                        doc: variant.doc.clone(),
+                        repr: match &variant.repr {
+                            FlatVariantRepresentation::String(repr) => {
+                                EnumVariantRepresentation::String(repr.clone())
+                            }
+                            FlatVariantRepresentation::Wrapped(inner) => {
+                                EnumVariantRepresentation::Wrapped(boxed(*inner))
+                            }
+                        },
+                    })
+                    .collect(),
+                non_exhaustive: *non_exhaustive,
+            },
+            FlatKind::OneOf(choices) => {
+                TypeKind::OneOf(choices.iter().map(|id| self.build(*id)).collect())
+            }
+            FlatKind::Ref(name) => TypeKind::Ref(name.clone()),
+        };
+
+        TypeDescription {
+            name: node.name.clone(),
+            kind,
+            doc: node.doc.clone(),
+            constraints: node.constraints.clone(),
+            examples: node.examples.clone(),
+            optional: node.optional,
+        }
+    }
+}
+
+impl TypeDescription {
+    /// Store the description as a [`FlatTypeDescription`]
+    #[must_use]
+    pub fn to_flat(&self) -> FlatTypeDescription {
+        let mut flat = FlatTypeDescription { nodes: vec![] };
+        flat.push(self);
+        flat
+    }
+
+    /// Build the tree of a [`FlatTypeDescription`] again
+    #[must_use]
+    pub fn from_flat(flat: &FlatTypeDescription) -> TypeDescription {
+        flat.build(0)
+    }
+}
+
+/// A single type in a [`FlatTypeDescription`]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct Node {
+    name: String,
+    kind: FlatKind,
+    doc: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    constraints: Vec<Constraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    examples: Vec<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    optional: bool,
+}
+
+impl Node {
+    /// Get the node's name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the node's kind.
+    #[must_use]
+    pub fn kind(&self) -> &FlatKind {
+        &self.kind
+    }
+
+    /// Get the node's documentation.
+    #[must_use]
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    /// Get the constraints the values of the node have to fulfill.
+    #[must_use]
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Get the example values of the node.
+    #[must_use]
+    pub fn examples(&self) -> &[Cow<'static, str>] {
+        &self.examples
+    }
+}
+
+/// The kind of a [`Node`], like [`TypeKind`] but with [`NodeId`]s instead of nested descriptions
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub enum FlatKind {
+    /// See [`TypeKind::Bool`]
+    Bool,
+    /// See [`TypeKind::Integer`]
+    Integer {
+        /// Sign of the integer
+        sign: Sign,
+        /// Size of the integer
+        size: u8,
+    },
+    /// See [`TypeKind::Float`]
+    Float {
+        /// The size of the value
+        size: u8,
+    },
+    /// See [`TypeKind::String`]
+    String,
+    /// See [`TypeKind::Char`]
+    Char,
+    /// See [`TypeKind::Bytes`]
+    Bytes,
+    /// See [`TypeKind::Unit`]
+    Unit,
+    /// See [`TypeKind::Any`]
+    Any,
+    /// See [`TypeKind::Wrapped`]
+    Wrapped(NodeId),
+    /// See [`TypeKind::Array`]
+    Array(NodeId),
+    /// See [`TypeKind::Tuple`]
+    Tuple(Vec<NodeId>),
+    /// See [`TypeKind::HashMap`]
+    HashMap {
+        /// The key of the HashMap
+        key: NodeId,
+        /// The value of the HashMap
+        value: NodeId,
+    },
+    /// See [`TypeKind::Struct`]
+    Struct(Vec<FlatField>),
+    /// See [`TypeKind::Enum`]
+    Enum {
+        /// How the variants are told apart
+        tagging: TypeEnumKind,
+        /// The variants of the enum
+        variants: Vec<FlatVariant>,
+        /// Whether more variants may be added later
+        non_exhaustive: bool,
+    },
+    /// See [`TypeKind::OneOf`]
+    OneOf(Vec<NodeId>),
+    /// See [`TypeKind::Ref`]
+    Ref(String),
+}
+
+impl FlatKind {
+    /// The ids of the nodes this kind contains
+    fn children(&self) -> Vec<NodeId> {
+        match self {
+            FlatKind::Bool
+            | FlatKind::Integer { .. }
+            | FlatKind::Float { .. }
+            | FlatKind::String
+            | FlatKind::Char
+            | FlatKind::Bytes
+            | FlatKind::Unit
+            | FlatKind::Any
+            | FlatKind::Ref(_) => vec![],
+            FlatKind::Wrapped(inner) | FlatKind::Array(inner) => vec![*inner],
+            FlatKind::Tuple(elements) | FlatKind::OneOf(elements) => elements.clone(),
+            FlatKind::HashMap { key, value } => vec![*key, *value],
+            FlatKind::Struct(fields) => fields.iter().map(|field| field.kind).collect(),
+            FlatKind::Enum { variants, .. } => variants
+                .iter()
+                .filter_map(|variant| match variant.repr {
+                    FlatVariantRepresentation::String(_) => None,
+                    FlatVariantRepresentation::Wrapped(inner) => Some(inner),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A field in a [`FlatKind::Struct`]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct FlatField {
+    name: Cow<'static, str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<Cow<'static, str>>,
+    doc: Option<Cow<'static, str>>,
+    kind: NodeId,
+    optional: bool,
+}
+
+impl FlatField {
+    /// Get the field's name.
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Get the name the field is documented with, see [`StructField::label`].
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Get the field's doc.
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    /// Get the id of the field's type.
+    pub fn kind(&self) -> NodeId {
+        self.kind
+    }
+
+    /// Whether this field is optional
+    pub fn optional(&self) -> bool {
+        self.optional
+    }
+}
+
+/// A variant in a [`FlatKind::Enum`]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct FlatVariant {
+    name: Cow<'static, str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<Cow<'static, str>>,
+    doc: Option<Cow<'static, str>>,
+    repr: FlatVariantRepresentation,
+}
+
+impl FlatVariant {
+    /// Get the variants's name.
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Get the name the variant is documented with, see [`EnumVariant::label`].
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Get the variants's doc.
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    /// Get the variants's representation.
+    pub fn repr(&self) -> &FlatVariantRepresentation {
+        &self.repr
+    }
+}
+
+/// Representation of a [`FlatVariant`], see [`EnumVariantRepresentation`]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub enum FlatVariantRepresentation {
+    /// The variant is represented by a string
+    String(Cow<'static, str>),
+    /// The variant is represented by the value of the node with this id
+    Wrapped(NodeId),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{AsTypeDescription, Constraint, StructBuilder, TypeDescription, TypeKind};
+
+    use super::{FlatKind, FlatTypeDescription};
+
+    #[test]
+    fn flat_round_trip() {
+        let tls = StructBuilder::new("Tls".into(), Some("TLS settings"))
+            .field(
+                "cert",
+                Some("Path to the certificate"),
+                String::as_type_description().with_constraints(vec![Constraint::MinLen(1)]),
+            )
+            .build();
+        let server = StructBuilder::new("Server".into(), Some("A server"))
+            .field("port", None, u16::as_type_description())
+            .field(
+                "limits",
+                None,
+                HashMap::<String, Vec<f64>>::as_type_description(),
+            )
+            .optional_field("tls", None, tls)
+            .field(
+                "result",
+                None,
+                Result::<(u8, char), Option<bool>>::as_type_description(),
+            )
+            .field(
+                "next",
+                None,
+                TypeDescription::new("Next".into(), TypeKind::Ref("Server".into()), None),
+            )
+            .build();
+
+        let flat = server.to_flat();
+        assert_eq!(flat.root().name(), "Server");
+        assert!(matches!(flat.root().kind(), FlatKind::Struct(fields) if fields.len() == 5));
+        assert_eq!(TypeDescription::from_flat(&flat), server);
+
+        let json = serde_json::to_string(&flat).unwrap();
+        assert_eq!(
+            serde_json::from_str::<FlatTypeDescription>(&json).unwrap(),
+            flat
+        );
+    }
+
+    #[test]
+    fn flat_rejects_invalid_nodes() {
+        let node = |kind: &str| format!(r#"{{ "name": "Node", "kind": {kind}, "doc": null }}"#);
+        let parse = |nodes: &[String]| {
+            serde_json::from_str::<FlatTypeDescription>(&format!(
+                r#"{{ "nodes": [{}] }}"#,
+                nodes.join(", ")
+            ))
+        };
+
+        assert!(parse(&[node(r#""Bool""#)]).is_ok());
+        assert!(parse(&[]).is_err());
+        // Missing, and cyclic
+        assert!(parse(&[node(r#"{ "Wrapped": 1 }"#)]).is_err());
+        assert!(parse(&[node(r#"{ "Wrapped": 0 }"#)]).is_err());
+        assert!(parse(&[node(r#"{ "Array": 1 }"#), node(r#"{ "Wrapped": 0 }"#)]).is_err());
+    }
+}
@@ -8,7 +8,12 @@
 
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    cell::RefCell,
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    num::Wrapping,
+    rc::Rc,
+    sync::Arc,
 };
 
 use serde::{Deserialize, Serialize};
@@ -16,8 +21,39 @@ use serde::{Deserialize, Serialize};
 /// A derive macro that helps implementing [`AsTypeDescription`]
 pub use type_description_derive::TypeDescription;
 
+/// Comparing [`struct@TypeDescription`]s with each other
+pub mod diff;
+
+/// Example configurations generated from [`struct@TypeDescription`]s
+#[cfg(any(feature = "yaml", feature = "toml"))]
+pub mod example;
+
+/// A flat representation of [`struct@TypeDescription`]s, for processing large descriptions
+pub mod flat;
+
+/// Combining partial [`struct@TypeDescription`]s
+pub mod merge;
+
+/// Checking values against [`struct@TypeDescription`]s
+#[cfg(feature = "json")]
+pub mod validate;
+
+/// Traversal of [`struct@TypeDescription`] trees
+pub mod visit;
+
 /// Rendering support for [`struct@TypeDescription`]s
-#[cfg(any(feature = "render_markdown", feature = "render_terminal"))]
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_plain",
+    feature = "render_html",
+    feature = "render_signature",
+    feature = "render_graphql",
+    feature = "render_rust",
+    feature = "render_proto",
+    feature = "render_table"
+))]
 pub mod render;
 
 /// Generic description of a type
@@ -26,6 +62,12 @@ pub struct TypeDescription {
     name: String,
     kind: TypeKind,
     doc: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    constraints: Vec<Constraint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    examples: Vec<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    optional: bool,
 }
 
 impl TypeDescription {
@@ -36,9 +78,66 @@ impl TypeDescription {
             name,
             kind,
             doc: doc.map(Cow::Borrowed),
+            constraints: vec![],
+            examples: vec![],
+            optional: false,
+        }
+    }
+
+    /// Construct the description of an `Option<T>`, which wraps the type of `T`
+    ///
+    /// Optional values may be absent or `null`, see [`TypeDescription::is_optional`].
+    #[must_use]
+    pub fn optional(inner: TypeDescription) -> Self {
+        Self {
+            optional: true,
+            ..Self::new(
+                format!("An optional '{}'", inner.name()),
+                TypeKind::Wrapped(Box::new(inner)),
+                None,
+            )
         }
     }
 
+    /// Construct a type description that accepts any one of the given types
+    #[must_use]
+    pub fn one_of(name: String, choices: Vec<TypeDescription>, doc: Option<&'static str>) -> Self {
+        Self::new(name, TypeKind::OneOf(choices), doc)
+    }
+
+    /// Replace the type's documentation with a possibly owned string, e.g. generated at runtime.
+    #[must_use]
+    pub fn with_doc(mut self, doc: impl Into<Cow<'static, str>>) -> Self {
+        self.doc = Some(doc.into());
+        self
+    }
+
+    /// Restrict the values of the type with the given constraints
+    #[must_use]
+    pub fn with_constraints(mut self, constraints: Vec<Constraint>) -> Self {
+        self.constraints.extend(constraints);
+        self
+    }
+
+    /// Get the constraints the values of the type have to fulfill.
+    #[must_use]
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Add example values, written the way they would appear in a configuration, e.g. `"8080"`
+    #[must_use]
+    pub fn with_examples(mut self, examples: Vec<Cow<'static, str>>) -> Self {
+        self.examples.extend(examples);
+        self
+    }
+
+    /// Get the example values of the type.
+    #[must_use]
+    pub fn examples(&self) -> &[Cow<'static, str>] {
+        &self.examples
+    }
+
     /// Get a reference to the type's documentation.
     #[must_use]
     pub fn doc(&self) -> Option<&str> {
@@ -51,11 +150,508 @@ impl TypeDescription {
         &self.kind
     }
 
+    /// Get the fields if the type is a [`TypeKind::Struct`]
+    #[must_use]
+    pub fn struct_fields(&self) -> Option<&[StructField]> {
+        match &self.kind {
+            TypeKind::Struct(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Get the variants if the type is a [`TypeKind::Enum`]
+    #[must_use]
+    pub fn enum_variants(&self) -> Option<&[EnumVariant]> {
+        match &self.kind {
+            TypeKind::Enum { variants, .. } => Some(variants),
+            _ => None,
+        }
+    }
+
+    /// Whether the type is a single value, i.e. a boolean, number, string, character or bytes
+    ///
+    /// Like the other category helpers, this only looks at the type itself, so a
+    /// [`TypeKind::Wrapped`] type is never primitive, even if it wraps one.
+    #[must_use]
+    pub fn is_primitive(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::Bool
+                | TypeKind::Integer { .. }
+                | TypeKind::Float { .. }
+                | TypeKind::String
+                | TypeKind::Char
+                | TypeKind::Bytes
+        )
+    }
+
+    /// Whether the type is made up of other types, i.e. a struct, enum, array, tuple or map
+    #[must_use]
+    pub fn is_composite(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::Struct(_)
+                | TypeKind::Enum { .. }
+                | TypeKind::Array(_)
+                | TypeKind::Tuple(_)
+                | TypeKind::HashMap { .. }
+        )
+    }
+
+    /// Whether the type holds any number of values of another type, i.e. an array or a map
+    #[must_use]
+    pub fn is_collection(&self) -> bool {
+        matches!(self.kind, TypeKind::Array(_) | TypeKind::HashMap { .. })
+    }
+
+    /// Whether the type is an `Option<T>` built with [`TypeDescription::optional`]
+    ///
+    /// This does not depend on the name of the type, so it survives e.g.
+    /// [`TypeDescription::map_names`].
+    #[must_use]
+    pub fn is_optional(&self) -> bool {
+        self.optional && matches!(self.kind, TypeKind::Wrapped(_))
+    }
+
     /// Get the type's name.
     #[must_use]
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Replace the type's name.
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    /// Replace the type's documentation.
+    pub fn set_doc(&mut self, doc: Option<&'static str>) {
+        self.doc = doc.map(Cow::Borrowed);
+    }
+
+    /// Serialize the description to a JSON string
+    #[cfg(feature = "json")]
+    pub fn to_json_string(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Serialize the description to a pretty-printed JSON string
+    #[cfg(feature = "json")]
+    pub fn to_json_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serialize the description as JSON directly into a writer, see [`write_json`]
+    #[cfg(feature = "json")]
+    pub fn write_json<W: std::io::Write>(&self, writer: W) -> serde_json::Result<()> {
+        write_json(self, writer)
+    }
+
+    /// Collect every primitive leaf of the type together with its path
+    ///
+    /// Struct fields, enum variants, tuple elements and choices of [`TypeKind::OneOf`] are
+    /// separated by `.`, array elements are written as `[]` and map values as `{}`, e.g.
+    /// `servers[].ports{}`. A primitive type itself has the empty path.
+    #[must_use]
+    pub fn leaves(&self) -> Vec<(String, &TypeKind)> {
+        let mut leaves = vec![];
+        collect_leaves(String::new(), self, &mut leaves);
+        leaves
+    }
+
+    /// Rewrite the names of the type, and of every nested type, struct field and enum variant
+    #[must_use]
+    pub fn map_names<F: Fn(&str) -> String>(mut self, f: F) -> TypeDescription {
+        map_names_in(&mut self, &f);
+        self
+    }
+
+    /// Remove the documentation of the type, and of every nested type, struct field and enum
+    /// variant
+    ///
+    /// This is useful when only the structure matters, e.g. to keep serialized descriptions small.
+    #[must_use]
+    pub fn without_docs(mut self) -> TypeDescription {
+        strip_docs_in(&mut self);
+        self
+    }
+
+    /// Compare two descriptions by their structure only, ignoring all documentation
+    ///
+    /// Names, kinds, constraints and examples are compared like with `==`, while the
+    /// documentation of types, struct fields and enum variants may differ.
+    #[must_use]
+    pub fn structurally_eq(&self, other: &TypeDescription) -> bool {
+        self.clone().without_docs() == other.clone().without_docs()
+    }
+
+    /// Get the maximum nesting depth of the type, where a type without children has depth 1
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        1 + self
+            .children()
+            .into_iter()
+            .map(TypeDescription::depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get the number of struct fields in the whole tree, including the fields of nested structs
+    #[must_use]
+    pub fn field_count(&self) -> usize {
+        let own = match self.kind() {
+            TypeKind::Struct(fields) => fields.len(),
+            _ => 0,
+        };
+        own + self
+            .children()
+            .into_iter()
+            .map(TypeDescription::field_count)
+            .sum::<usize>()
+    }
+
+    /// Check whether the type, or any type nested in it, has a kind matching the predicate
+    ///
+    /// This stops at the first match.
+    #[must_use]
+    pub fn contains_kind(&self, predicate: impl Fn(&TypeKind) -> bool) -> bool {
+        self.contains_kind_by(&predicate)
+    }
+
+    fn contains_kind_by(&self, predicate: &dyn Fn(&TypeKind) -> bool) -> bool {
+        predicate(self.kind())
+            || self
+                .children()
+                .into_iter()
+                .any(|child| child.contains_kind_by(predicate))
+    }
+
+    /// The descriptions directly nested in this one
+    fn children(&self) -> Vec<&TypeDescription> {
+        match self.kind() {
+            TypeKind::Bool
+            | TypeKind::Integer { .. }
+            | TypeKind::Float { .. }
+            | TypeKind::String
+            | TypeKind::Char
+            | TypeKind::Bytes
+            | TypeKind::Unit
+            | TypeKind::Any
+            | TypeKind::Ref(_) => vec![],
+            TypeKind::Wrapped(inner) | TypeKind::Array(inner) => vec![inner],
+            TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => elements.iter().collect(),
+            TypeKind::HashMap { key, value } => vec![key, value],
+            TypeKind::Struct(fields) => fields.iter().map(StructField::kind).collect(),
+            TypeKind::Enum { variants, .. } => variants
+                .iter()
+                .filter_map(|variant| match variant.repr() {
+                    EnumVariantRepresentation::String(_) => None,
+                    EnumVariantRepresentation::Wrapped(inner) => Some(&**inner),
+                })
+                .collect(),
+        }
+    }
+
+    /// The descriptions directly nested in this one, for modifying them
+    fn children_mut(&mut self) -> Vec<&mut TypeDescription> {
+        match &mut self.kind {
+            TypeKind::Bool
+            | TypeKind::Integer { .. }
+            | TypeKind::Float { .. }
+            | TypeKind::String
+            | TypeKind::Char
+            | TypeKind::Bytes
+            | TypeKind::Unit
+            | TypeKind::Any
+            | TypeKind::Ref(_) => vec![],
+            TypeKind::Wrapped(inner) | TypeKind::Array(inner) => vec![inner],
+            TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => elements.iter_mut().collect(),
+            TypeKind::HashMap { key, value } => vec![key, value],
+            TypeKind::Struct(fields) => fields.iter_mut().map(|field| &mut field.kind).collect(),
+            TypeKind::Enum { variants, .. } => variants
+                .iter_mut()
+                .filter_map(|variant| match &mut variant.repr {
+                    EnumVariantRepresentation::String(_) => None,
+                    EnumVariantRepresentation::Wrapped(inner) => Some(&mut **inner),
+                })
+                .collect(),
+        }
+    }
+
+    /// Get a mutable reference to the type's kind.
+    #[must_use]
+    pub fn kind_mut(&mut self) -> &mut TypeKind {
+        &mut self.kind
+    }
+
+    /// Simplify the tree by removing layers that do not change how a value is written
+    ///
+    /// The rules, applied from the leaves upwards, are:
+    ///
+    /// - An optional of an optional becomes a single optional, as serde writes both the same way
+    /// - A [`TypeKind::Wrapped`] without documentation or constraints, that has the same name as
+    ///   the type it wraps, is replaced by that type
+    /// - A [`TypeKind::OneOf`] choice that is itself an undocumented, unconstrained
+    ///   [`TypeKind::OneOf`] is replaced by its choices
+    /// - An undocumented, unconstrained [`TypeKind::OneOf`] with a single choice is replaced by
+    ///   that choice
+    ///
+    /// Wrappers that give a type its own name or documentation, like a `Port` wrapping a `u16`,
+    /// are kept. Smart pointers like `Box` or `Arc` need no normalization, they are described
+    /// like the type they point to in the first place.
+    #[must_use]
+    pub fn normalize(mut self) -> TypeDescription {
+        normalize_in(&mut self);
+        self
+    }
+
+    /// Whether the type adds nothing to the way it is written apart from its name
+    fn is_plain(&self) -> bool {
+        self.doc.is_none() && self.constraints.is_empty() && self.examples.is_empty()
+    }
+}
+
+fn normalize_in(desc: &mut TypeDescription) {
+    for child in desc.children_mut() {
+        normalize_in(child);
+    }
+
+    let plain = desc.is_plain();
+    match &mut desc.kind {
+        TypeKind::Wrapped(inner) => {
+            let nested_optional = desc.optional && inner.is_optional();
+            let redundant = plain && desc.name == inner.name;
+            if nested_optional || redundant {
+                *desc = std::mem::replace(&mut **inner, TypeDescription::from(TypeKind::Unit));
+            }
+        }
+        TypeKind::OneOf(choices) => {
+            *choices = std::mem::take(choices)
+                .into_iter()
+                .flat_map(|choice| match choice {
+                    TypeDescription {
+                        kind: TypeKind::OneOf(nested),
+                        doc: None,
+                        ref constraints,
+                        ref examples,
+                        ..
+                    } if constraints.is_empty() && examples.is_empty() => nested,
+                    choice => vec![choice],
+                })
+                .collect();
+            if choices.len() == 1 && plain {
+                *desc = choices.remove(0);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn map_names_in<F: Fn(&str) -> String>(desc: &mut TypeDescription, f: &F) {
+    desc.name = f(&desc.name);
+
+    match &mut desc.kind {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Unit
+        | TypeKind::Any => (),
+        TypeKind::Ref(name) => *name = f(name),
+        TypeKind::Wrapped(inner) | TypeKind::Array(inner) => map_names_in(inner, f),
+        TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => {
+            for element in elements {
+                map_names_in(element, f);
+            }
+        }
+        TypeKind::HashMap { key, value } => {
+            map_names_in(key, f);
+            map_names_in(value, f);
+        }
+        TypeKind::Struct(fields) => {
+            for field in fields {
+                field.name = Cow::Owned(f(&field.name));
+                map_names_in(&mut field.kind, f);
+            }
+        }
+        TypeKind::Enum { variants, .. } => {
+            for variant in variants {
+                variant.name = Cow::Owned(f(&variant.name));
+                if let EnumVariantRepresentation::Wrapped(inner) = &mut variant.repr {
+                    map_names_in(inner, f);
+                }
+            }
+        }
+    }
+}
+
+fn strip_docs_in(desc: &mut TypeDescription) {
+    desc.doc = None;
+
+    match &mut desc.kind {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Unit
+        | TypeKind::Any
+        | TypeKind::Ref(_) => (),
+        TypeKind::Wrapped(inner) | TypeKind::Array(inner) => strip_docs_in(inner),
+        TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => {
+            elements.iter_mut().for_each(strip_docs_in)
+        }
+        TypeKind::HashMap { key, value } => {
+            strip_docs_in(key);
+            strip_docs_in(value);
+        }
+        TypeKind::Struct(fields) => {
+            for field in fields {
+                field.doc = None;
+                strip_docs_in(&mut field.kind);
+            }
+        }
+        TypeKind::Enum { variants, .. } => {
+            for variant in variants {
+                variant.doc = None;
+                if let EnumVariantRepresentation::Wrapped(inner) = &mut variant.repr {
+                    strip_docs_in(inner);
+                }
+            }
+        }
+    }
+}
+
+/// Append a struct field, enum variant or position to a path written like the ones of
+/// [`TypeDescription::leaves`]
+pub(crate) fn join_path(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_string()
+    } else {
+        format!("{path}.{segment}")
+    }
+}
+
+fn collect_leaves<'d>(
+    path: String,
+    desc: &'d TypeDescription,
+    leaves: &mut Vec<(String, &'d TypeKind)>,
+) {
+    match desc.kind() {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Unit
+        | TypeKind::Any => leaves.push((path, desc.kind())),
+        TypeKind::Ref(_) => (),
+        TypeKind::Wrapped(inner) => collect_leaves(path, inner, leaves),
+        TypeKind::Array(inner) => collect_leaves(format!("{path}[]"), inner, leaves),
+        TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => {
+            for (idx, element) in elements.iter().enumerate() {
+                collect_leaves(join_path(&path, &idx.to_string()), element, leaves);
+            }
+        }
+        TypeKind::HashMap { value, .. } => collect_leaves(format!("{path}{{}}"), value, leaves),
+        TypeKind::Struct(fields) => {
+            for field in fields {
+                collect_leaves(join_path(&path, field.name()), field.kind(), leaves);
+            }
+        }
+        TypeKind::Enum { variants, .. } => {
+            for variant in variants {
+                if let EnumVariantRepresentation::Wrapped(inner) = variant.repr() {
+                    collect_leaves(join_path(&path, variant.name()), inner, leaves);
+                }
+            }
+        }
+    }
+}
+
+/// Describe a type that may contain itself, without recursing forever
+///
+/// While `describe` runs, any further attempt to describe `T` on the same thread yields a
+/// [`TypeKind::Ref`] with the given name instead. The derive macro does this for every type, so
+/// hand-written implementations only need it for types that can contain themselves.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{describe_recursive, AsTypeDescription, StructBuilder, TypeDescription, TypeKind};
+///
+///     struct Tree {
+///         children: Vec<Tree>,
+///     }
+///
+///     impl AsTypeDescription for Tree {
+///         fn as_type_description() -> TypeDescription {
+///             describe_recursive::<Self>("Tree", || {
+///                 StructBuilder::new("Tree".into(), None)
+///                     .field("children", None, Vec::<Tree>::as_type_description())
+///                     .build()
+///             })
+///         }
+///     }
+///
+///     assert!(Tree::as_type_description().contains_kind(|kind| kind == &TypeKind::Ref("Tree".into())));
+/// ```
+pub fn describe_recursive<T: ?Sized>(
+    name: &str,
+    describe: impl FnOnce() -> TypeDescription,
+) -> TypeDescription {
+    thread_local! {
+        static IN_PROGRESS: RefCell<Vec<&'static str>> = RefCell::new(vec![]);
+    }
+
+    /// Removes the type from the types in progress again, even if describing it panicked
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            IN_PROGRESS.with(|in_progress| in_progress.borrow_mut().pop());
+        }
+    }
+
+    let key = std::any::type_name::<T>();
+    if IN_PROGRESS.with(|in_progress| in_progress.borrow().contains(&key)) {
+        return TypeDescription::new(name.into(), TypeKind::Ref(name.into()), None);
+    }
+
+    IN_PROGRESS.with(|in_progress| in_progress.borrow_mut().push(key));
+    let _guard = Guard;
+    describe()
+}
+
+/// A restriction on the values a [`struct@TypeDescription`] accepts
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub enum Constraint {
+    /// The value has to be at least this large
+    Min(i64),
+    /// The value has to be at most this large
+    Max(i64),
+    /// The value has to have at least this many elements or characters
+    MinLen(usize),
+    /// The value has to have at most this many elements or characters
+    MaxLen(usize),
+    /// The value has to match this regular expression
+    Pattern(Cow<'static, str>),
+}
+
+impl std::fmt::Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Constraint::Min(min) => write!(f, "minimum {min}"),
+            Constraint::Max(max) => write!(f, "maximum {max}"),
+            Constraint::MinLen(min) => write!(f, "minimum length {min}"),
+            Constraint::MaxLen(max) => write!(f, "maximum length {max}"),
+            Constraint::Pattern(pattern) => write!(f, "matching `{pattern}`"),
+        }
+    }
 }
 
 /// Representation of an enum
@@ -74,14 +670,28 @@ pub enum EnumVariantRepresentation {
 pub enum TypeEnumKind {
     /// An internal tag with the given tag name
     Tagged(Cow<'static, str>),
+    /// An adjacent tag, where the variant name and its content are two fields next to each other
+    Adjacent {
+        /// The name of the field that holds the variant name
+        tag: Cow<'static, str>,
+        /// The name of the field that holds the content of the variant, if it has any
+        content: Cow<'static, str>,
+    },
     /// An untagged enum variant
     Untagged,
+    /// An externally tagged enum variant, which is serde's default
+    ///
+    /// Variants with content are represented as a map with the variant name as the only key
+    External,
 }
 
 /// A field in a [`TypeKind::Struct`]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub struct StructField {
     name: Cow<'static, str>,
+    /// The name to document the field with, if it differs from the serialized name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<Cow<'static, str>>,
     doc: Option<Cow<'static, str>>,
     kind: TypeDescription,
     optional: bool,
@@ -97,17 +707,51 @@ impl StructField {
     ) -> Self {
         Self {
             name: Cow::Borrowed(name),
+            label: None,
             doc: doc.map(Cow::Borrowed),
             kind,
             optional,
         }
     }
 
-    /// Get the field's name.
+    /// Replace the field's name with a possibly owned string
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Replace the field's doc with a possibly owned string
+    #[must_use]
+    pub fn with_doc(mut self, doc: impl Into<Cow<'static, str>>) -> Self {
+        self.doc = Some(doc.into());
+        self
+    }
+
+    /// Replace whether the field can be left out
+    #[must_use]
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Document the field with a different name than the one it is serialized with
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Get the field's name, as it is serialized.
     pub fn name(&self) -> &str {
         self.name.as_ref()
     }
 
+    /// Get the name the field is documented with, which is its name unless a label is set.
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
     /// Get the field's doc.
     pub fn doc(&self) -> Option<&str> {
         self.doc.as_deref()
@@ -124,10 +768,77 @@ impl StructField {
     }
 }
 
+/// A builder for [`struct@TypeDescription`]s of [`TypeKind::Struct`]
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::StructBuilder;
+///
+///     let desc = StructBuilder::new("Config".into(), Some("A configuration"))
+///         .field("port", Some("The port to listen on"), u16::as_type_description())
+///         .optional_field("name", None, String::as_type_description())
+///         .build();
+///
+///     assert_eq!(desc.name(), "Config");
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructBuilder {
+    name: String,
+    doc: Option<&'static str>,
+    fields: Vec<StructField>,
+}
+
+impl StructBuilder {
+    /// Start building a struct with the given name and documentation
+    #[must_use]
+    pub fn new(name: String, doc: Option<&'static str>) -> Self {
+        Self {
+            name,
+            doc,
+            fields: vec![],
+        }
+    }
+
+    /// Add a required field
+    #[must_use]
+    pub fn field(
+        mut self,
+        name: &'static str,
+        doc: Option<&'static str>,
+        kind: TypeDescription,
+    ) -> Self {
+        self.fields.push(StructField::new(name, doc, kind, false));
+        self
+    }
+
+    /// Add an optional field
+    #[must_use]
+    pub fn optional_field(
+        mut self,
+        name: &'static str,
+        doc: Option<&'static str>,
+        kind: TypeDescription,
+    ) -> Self {
+        self.fields.push(StructField::new(name, doc, kind, true));
+        self
+    }
+
+    /// Finish building the [`struct@TypeDescription`]
+    #[must_use]
+    pub fn build(self) -> TypeDescription {
+        TypeDescription::new(self.name, TypeKind::Struct(self.fields), self.doc)
+    }
+}
+
 /// A variant in a [`TypeKind::Enum`]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub struct EnumVariant {
     name: Cow<'static, str>,
+    /// The name to document the variant with, if it differs from the serialized name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<Cow<'static, str>>,
     doc: Option<Cow<'static, str>>,
     repr: EnumVariantRepresentation,
 }
@@ -141,16 +852,43 @@ impl EnumVariant {
     ) -> Self {
         Self {
             name: Cow::Borrowed(name),
+            label: None,
             doc: doc.map(Cow::Borrowed),
             repr,
         }
     }
 
-    /// Get the variants's name.
+    /// Replace the variant's name with a possibly owned string
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Replace the variant's doc with a possibly owned string
+    #[must_use]
+    pub fn with_doc(mut self, doc: impl Into<Cow<'static, str>>) -> Self {
+        self.doc = Some(doc.into());
+        self
+    }
+
+    /// Document the variant with a different name than the one it is serialized with
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Get the variants's name, as it is serialized.
     pub fn name(&self) -> &str {
         self.name.as_ref()
     }
 
+    /// Get the name the variant is documented with, which is its name unless a label is set.
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
     /// Get the variants's doc.
     pub fn doc(&self) -> Option<&str> {
         self.doc.as_deref()
@@ -162,6 +900,21 @@ impl EnumVariant {
     }
 }
 
+/// Binary data that is written as a base64 encoded string
+///
+/// This type only exists to be described as [`TypeKind::Bytes`], the encoding itself is left to
+/// whatever (de)serializes it. Fields of other types, e.g. `Vec<u8>`, can be described as bytes
+/// with `#[description(kind = "Bytes")]` when deriving.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct Bytes(pub Vec<u8>);
+
+/// A value that is accepted in any shape
+///
+/// This type only exists to be described as [`TypeKind::Any`]. Fields that are passed on without
+/// being inspected can be described like this with `#[description(kind = "Any")]` when deriving.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct AnyValue;
+
 /// The specific kind a [`struct@TypeDescription`] represents
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub enum TypeKind {
@@ -186,6 +939,18 @@ pub enum TypeKind {
     /// Type represents a string
     String,
 
+    /// Type represents a single character `'a', 'ß', '🦀'`
+    Char,
+
+    /// Type represents binary data, written as a base64 encoded string
+    Bytes,
+
+    /// Type represents the absence of a value, like `()` or a unit struct
+    Unit,
+
+    /// Type accepts any value, e.g. for opaque data that is passed on without being inspected
+    Any,
+
     /// Wrap another type
     ///
     /// This is particularly useful if you want to restrict another kind. The common example is a
@@ -196,6 +961,9 @@ pub enum TypeKind {
     /// Type represents an array of values of the given [`TypeKind`]
     Array(Box<TypeDescription>),
 
+    /// Type represents a fixed-size sequence of values, each with their own type
+    Tuple(Vec<TypeDescription>),
+
     /// Type represents a hashmap of named types of the same type
     HashMap {
         /// The key of the HashMap
@@ -208,20 +976,128 @@ pub enum TypeKind {
     Struct(Vec<StructField>),
 
     /// Type represents multiple choice of type variants
-    Enum(TypeEnumKind, Vec<EnumVariant>),
-}
+    Enum {
+        /// How the variants are told apart
+        tagging: TypeEnumKind,
+        /// The variants of the enum
+        variants: Vec<EnumVariant>,
+        /// Whether more variants may be added later, as for `#[non_exhaustive]` enums
+        non_exhaustive: bool,
+    },
 
-/// Whether an integer is a signed integer or an unsigned integer
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
-pub enum Sign {
-    /// A signed integer
-    Signed,
+    /// Type represents any one of the given types, without naming the choices
+    ///
+    /// This is what an untagged enum with differently shaped variants is written as, e.g. a
+    /// setting that accepts either a plain string or a detailed table.
+    OneOf(Vec<TypeDescription>),
 
-    /// An unsigned integer
-    Unsigned,
+    /// Type refers back to an enclosing type with the given name
+    ///
+    /// This is how recursive types are described, see [`describe_recursive`].
+    Ref(String),
 }
 
-/// Turn a Rust type into a [`struct@TypeDescription`] object
+impl TypeKind {
+    /// Get a short tag for the kind, like `"struct"` or `"hashmap"`
+    ///
+    /// Unlike the name of a [`struct@TypeDescription`], the tag only depends on the variant and
+    /// does not change between versions, which makes it suitable for grouping and matching.
+    #[must_use]
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            TypeKind::Bool => "bool",
+            TypeKind::Integer { .. } => "integer",
+            TypeKind::Float { .. } => "float",
+            TypeKind::String => "string",
+            TypeKind::Char => "char",
+            TypeKind::Bytes => "bytes",
+            TypeKind::Unit => "unit",
+            TypeKind::Any => "any",
+            TypeKind::Wrapped(_) => "wrapped",
+            TypeKind::Array(_) => "array",
+            TypeKind::Tuple(_) => "tuple",
+            TypeKind::HashMap { .. } => "hashmap",
+            TypeKind::Struct(_) => "struct",
+            TypeKind::Enum { .. } => "enum",
+            TypeKind::OneOf(_) => "one_of",
+            TypeKind::Ref(_) => "ref",
+        }
+    }
+}
+
+/// Describe a hand-built kind with a default name
+///
+/// Primitive kinds also get the documentation of the matching Rust type, e.g.
+/// `TypeDescription::from(TypeKind::Bool)` is the same as `bool::as_type_description()`.
+impl From<TypeKind> for TypeDescription {
+    fn from(kind: TypeKind) -> Self {
+        fn names(elements: &[TypeDescription]) -> String {
+            elements
+                .iter()
+                .map(|element| format!("'{}'", element.name()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        let (name, doc): (String, Option<Cow<'static, str>>) = match &kind {
+            TypeKind::Bool => ("Boolean".into(), Some("A boolean".into())),
+            TypeKind::Integer { sign, size } => (
+                "Integer".into(),
+                Some(
+                    match sign {
+                        Sign::Signed => format!("A signed integer with {size} bits"),
+                        Sign::Unsigned => format!("An unsigned integer with {size} bits"),
+                    }
+                    .into(),
+                ),
+            ),
+            TypeKind::Float { size } => (
+                "Float".into(),
+                Some(format!("A floating point value with {size} bits").into()),
+            ),
+            TypeKind::String => ("String".into(), Some("An UTF-8 string".into())),
+            TypeKind::Char => ("Char".into(), Some("A single Unicode scalar value".into())),
+            TypeKind::Bytes => (
+                "Bytes".into(),
+                Some("Binary data, encoded as a base64 string".into()),
+            ),
+            TypeKind::Unit => ("Unit".into(), Some("The unit value `()`".into())),
+            TypeKind::Any => ("Any".into(), Some("Any value".into())),
+            TypeKind::Wrapped(inner) => (inner.name().to_string(), None),
+            TypeKind::Array(inner) => (format!("Array of '{}'s", inner.name()), None),
+            TypeKind::Tuple(elements) => (format!("Tuple of ({})", names(elements)), None),
+            TypeKind::HashMap { key, value } => (
+                format!("Table of '{} => {}'", key.name(), value.name()),
+                None,
+            ),
+            TypeKind::Struct(_) => ("Struct".into(), None),
+            TypeKind::Enum { .. } => ("Enum".into(), None),
+            TypeKind::OneOf(choices) => (format!("One of ({})", names(choices)), None),
+            TypeKind::Ref(name) => (name.clone(), None),
+        };
+
+        TypeDescription {
+            name,
+            kind,
+            doc,
+            constraints: vec![],
+            examples: vec![],
+            optional: false,
+        }
+    }
+}
+
+/// Whether an integer is a signed integer or an unsigned integer
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub enum Sign {
+    /// A signed integer
+    Signed,
+
+    /// An unsigned integer
+    Unsigned,
+}
+
+/// Turn a Rust type into a [`struct@TypeDescription`] object
 ///
 /// Crate authors can either implement this manually or use the [`derive@TypeDescription`] derive
 /// macro.
@@ -230,11 +1106,114 @@ pub trait AsTypeDescription {
     fn as_type_description() -> TypeDescription;
 }
 
+/// Get the [`struct@TypeDescription`] of `T`
+///
+/// This is the same as calling [`AsTypeDescription::as_type_description`], but does not need the
+/// trait in scope.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{description_for, TypeDescription};
+///
+///     #[derive(TypeDescription)]
+///     struct ServerConfig {
+///         port: u16,
+///     }
+///
+///     let desc = description_for::<ServerConfig>();
+///     assert_eq!(desc.name(), "ServerConfig");
+///     assert_eq!(desc.struct_fields().unwrap()[0].name(), "port");
+/// ```
+pub fn description_for<T: AsTypeDescription + ?Sized>() -> TypeDescription {
+    T::as_type_description()
+}
+
+/// Serialize a [`struct@TypeDescription`] as JSON directly into a writer
+///
+/// Writes the same JSON as [`TypeDescription::to_json_string`], without building the whole string
+/// in memory first, like `render::write_markdown` does for Markdown.
+#[cfg(feature = "json")]
+pub fn write_json<W: std::io::Write>(desc: &TypeDescription, writer: W) -> serde_json::Result<()> {
+    serde_json::to_writer(writer, desc)
+}
+
+macro_rules! impl_transparent {
+    ($($typ:ident),+) => {
+        $(
+            impl<T: AsTypeDescription> AsTypeDescription for $typ<T> {
+                fn as_type_description() -> TypeDescription {
+                    T::as_type_description()
+                }
+            }
+        )+
+    };
+}
+
+// `std::num::Saturating` belongs here as well, once the minimum supported Rust version is at least
+// 1.74, where it was stabilized
+impl_transparent!(Box, Rc, Arc, Wrapping, Reverse);
+
+impl AsTypeDescription for Cow<'_, str> {
+    fn as_type_description() -> TypeDescription {
+        String::as_type_description()
+    }
+}
+
+impl<T: AsTypeDescription + Clone> AsTypeDescription for Cow<'_, [T]> {
+    fn as_type_description() -> TypeDescription {
+        Vec::<T>::as_type_description()
+    }
+}
+
+/// A `PhantomData` carries no value, serde writes it like `()`
+///
+/// The derive macro leaves out struct fields of this type entirely.
+impl<T: ?Sized> AsTypeDescription for std::marker::PhantomData<T> {
+    fn as_type_description() -> TypeDescription {
+        <()>::as_type_description()
+    }
+}
+
+/// Nested options like `Option<Option<T>>` collapse into a single optional layer, as serde writes
+/// both `None` and `Some(None)` as an absent value
 impl<T: AsTypeDescription> AsTypeDescription for Option<T> {
+    fn as_type_description() -> TypeDescription {
+        let inner = T::as_type_description();
+        if inner.is_optional() {
+            inner
+        } else {
+            TypeDescription::optional(inner)
+        }
+    }
+}
+
+/// A `Result` is described the way serde represents it: as an externally tagged enum, so either
+/// `{ "Ok": <T> }` or `{ "Err": <E> }`
+impl<T: AsTypeDescription, E: AsTypeDescription> AsTypeDescription for Result<T, E> {
     fn as_type_description() -> TypeDescription {
         TypeDescription::new(
-            format!("An optional '{}'", T::as_type_description().name()),
-            TypeKind::Wrapped(Box::new(T::as_type_description())),
+            format!(
+                "Either '{}' or '{}'",
+                T::as_type_description().name(),
+                E::as_type_description().name()
+            ),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::External,
+                variants: vec![
+                    EnumVariant::new(
+                        "Ok",
+                        Some("The successful value"),
+                        EnumVariantRepresentation::Wrapped(Box::new(T::as_type_description())),
+                    ),
+                    EnumVariant::new(
+                        "Err",
+                        Some("The error value"),
+                        EnumVariantRepresentation::Wrapped(Box::new(E::as_type_description())),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
             None,
         )
     }
@@ -250,6 +1229,30 @@ impl<T: AsTypeDescription> AsTypeDescription for Vec<T> {
     }
 }
 
+impl<T: AsTypeDescription> AsTypeDescription for VecDeque<T> {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new(
+            format!("Array of '{}'s", T::as_type_description().name()),
+            TypeKind::Array(Box::new(T::as_type_description())),
+            None,
+        )
+    }
+}
+
+impl<T: AsTypeDescription, const N: usize> AsTypeDescription for [T; N] {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new(
+            format!(
+                "Array of '{}'s with length {}",
+                T::as_type_description().name(),
+                N
+            ),
+            TypeKind::Array(Box::new(T::as_type_description())),
+            None,
+        )
+    }
+}
+
 impl<K: AsTypeDescription, V: AsTypeDescription> AsTypeDescription for HashMap<K, V> {
     fn as_type_description() -> TypeDescription {
         TypeDescription::new(
@@ -304,6 +1307,64 @@ impl<T: AsTypeDescription> AsTypeDescription for BTreeSet<T> {
     }
 }
 
+impl AsTypeDescription for std::time::Duration {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new(
+            "Duration".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "secs",
+                    Some("The whole seconds of the duration"),
+                    u64::as_type_description(),
+                    false,
+                ),
+                StructField::new(
+                    "nanos",
+                    Some("The additional nanoseconds, always less than one second"),
+                    u32::as_type_description(),
+                    false,
+                ),
+            ]),
+            Some("A span of time, made up of seconds and nanoseconds"),
+        )
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($typ:ident),+) => {
+        impl<$($typ: AsTypeDescription),+> AsTypeDescription for ($($typ,)+) {
+            fn as_type_description() -> TypeDescription {
+                let elements = vec![$($typ::as_type_description()),+];
+                TypeDescription::new(
+                    format!(
+                        "Tuple of ({})",
+                        elements
+                            .iter()
+                            .map(|element| format!("'{}'", element.name()))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    TypeKind::Tuple(elements),
+                    None,
+                )
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
+impl_tuple!(A, B, C, D, E, F, G);
+impl_tuple!(A, B, C, D, E, F, G, H);
+impl_tuple!(A, B, C, D, E, F, G, H, I);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 macro_rules! impl_config_kind {
     ($kind:expr; $name:expr; $doc:expr => $($typ:ty),+) => {
         $(
@@ -341,27 +1402,139 @@ impl_config_kind!(TypeKind::Float { size: 32 }; "Float"; "A floating point value
 
 impl_config_kind!(TypeKind::Bool; "Boolean"; "A boolean" => bool);
 impl_config_kind!(TypeKind::String; "String"; "An UTF-8 string" => String);
+impl_config_kind!(TypeKind::Char; "Char"; "A single Unicode scalar value" => char);
+impl_config_kind!(TypeKind::Bytes; "Bytes"; "Binary data, encoded as a base64 string" => Bytes);
+impl_config_kind!(TypeKind::Unit; "Unit"; "The unit value `()`" => ());
+
+impl_config_kind!(TypeKind::Any; "Any"; "Any value" => AnyValue);
+#[cfg(feature = "json")]
+impl_config_kind!(TypeKind::Any; "Any"; "Any JSON value" => serde_json::Value);
+
+impl_config_kind!(TypeKind::String; "String"; "An IP address" => std::net::IpAddr);
+impl_config_kind!(TypeKind::String; "String"; "An IPv4 address" => std::net::Ipv4Addr);
+impl_config_kind!(TypeKind::String; "String"; "An IPv6 address" => std::net::Ipv6Addr);
 
 impl_config_kind!(TypeKind::String; "String"; "A socket address" => std::net::SocketAddr);
 impl_config_kind!(TypeKind::String; "String"; "An IPv4 socket address" => std::net::SocketAddrV4);
 impl_config_kind!(TypeKind::String; "String"; "An IPv6 socket address" => std::net::SocketAddrV6);
 
+macro_rules! impl_range {
+    ($range:ident, $name:literal, $doc:literal, $end_doc:literal) => {
+        /// Ranges are described the way serde represents them, as a table with a `start` and an
+        /// `end`
+        impl<T: AsTypeDescription> AsTypeDescription for std::ops::$range<T> {
+            fn as_type_description() -> TypeDescription {
+                TypeDescription::new(
+                    format!($name, T::as_type_description().name()),
+                    TypeKind::Struct(vec![
+                        StructField::new(
+                            "start",
+                            Some("The lower bound, which is included"),
+                            T::as_type_description(),
+                            false,
+                        ),
+                        StructField::new("end", Some($end_doc), T::as_type_description(), false),
+                    ]),
+                    Some($doc),
+                )
+            }
+        }
+    };
+}
+
+impl_range!(
+    Range,
+    "Range of '{}'s",
+    "A half-open range, which includes the start but not the end",
+    "The upper bound, which is excluded"
+);
+impl_range!(
+    RangeInclusive,
+    "Inclusive range of '{}'s",
+    "An inclusive range, which includes both the start and the end",
+    "The upper bound, which is included"
+);
+
+/// A `SystemTime` is described the way serde represents it, as the time since the Unix epoch
+impl AsTypeDescription for std::time::SystemTime {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new(
+            "SystemTime".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "secs_since_epoch",
+                    Some("The whole seconds since the Unix epoch"),
+                    u64::as_type_description(),
+                    false,
+                ),
+                StructField::new(
+                    "nanos_since_epoch",
+                    Some("The additional nanoseconds, always less than one second"),
+                    u32::as_type_description(),
+                    false,
+                ),
+            ]),
+            Some("A point in time, counted from the Unix epoch"),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> AsTypeDescription for chrono::DateTime<Tz> {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new(
+            "String".into(),
+            TypeKind::String,
+            Some("An RFC 3339 timestamp"),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl_config_kind!(TypeKind::String; "String"; "A date like `2015-09-05`" => chrono::NaiveDate);
+
+#[cfg(feature = "chrono")]
+impl_config_kind!(TypeKind::String; "String"; "A date and time without timezone like `2015-09-05T23:56:04`" => chrono::NaiveDateTime);
+
+#[cfg(feature = "chrono")]
+impl_config_kind!(TypeKind::String; "String"; "A time without timezone like `23:56:04`" => chrono::NaiveTime);
+
+/// A `ByteSize` is written as a human readable string with a unit, e.g. `"10 MB"`
 #[cfg(feature = "bytesize")]
-impl_config_kind!(TypeKind::String; "String"; "A number of Bytes" => bytesize::ByteSize);
+impl AsTypeDescription for bytesize::ByteSize {
+    fn as_type_description() -> TypeDescription {
+        TypeDescription::new(
+            "ByteSize".into(),
+            TypeKind::Wrapped(Box::new(String::as_type_description())),
+            Some("A number of bytes, written with a unit like \"10 MB\" or \"4 KiB\""),
+        )
+    }
+}
+
+// Unlike `std::time::Duration`, this is written as a string. Fields that use `humantime_serde`
+// are still a `std::time::Duration` and need `#[description(kind = "String")]` instead.
+#[cfg(feature = "humantime")]
+impl_config_kind!(TypeKind::String; "String"; "A human-readable duration like '30s' or '5m'" => humantime::Duration);
 
 #[cfg(feature = "url")]
-impl_config_kind!(TypeKind::String; "String"; "An URL" => url::Url);
+impl_config_kind!(TypeKind::String; "String"; "A URL" => url::Url);
 
 #[cfg(feature = "uuid")]
 impl_config_kind!(TypeKind::String; "String"; "A UUID" => uuid::Uuid);
 
-impl_config_kind!(TypeKind::String; "String"; "A filesystem path" => std::path::PathBuf);
+impl_config_kind!(TypeKind::String; "String"; "A filesystem path" => std::path::PathBuf, &std::path::Path);
+impl_config_kind!(TypeKind::String; "String"; "A platform string, which does not have to be valid UTF-8 on every platform" => std::ffi::OsString, &std::ffi::OsStr);
+impl_config_kind!(TypeKind::String; "String"; "A string without interior nul bytes, as used by C" => std::ffi::CString, &std::ffi::CStr);
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+    use std::time::Duration;
 
-    use super::{AsTypeDescription, Sign, TypeDescription, TypeKind};
+    use super::{
+        AsTypeDescription, Constraint, EnumVariant, EnumVariantRepresentation, Sign, StructBuilder,
+        StructField, TypeDescription, TypeEnumKind, TypeKind,
+    };
 
     #[test]
     fn verify_correct_config_kinds() {
@@ -380,6 +1553,20 @@ mod tests {
         assert!(
             matches!(complex_config.kind(), TypeKind::HashMap { value, .. } if matches!(value.kind(), TypeKind::Array(arr) if matches!(arr.kind(), TypeKind::HashMap { value, .. } if matches!(value.kind(), TypeKind::String))))
         );
+
+        assert!(matches!(
+            Vec::<char>::as_type_description(),
+            TypeDescription {
+                doc: None,
+                kind: TypeKind::Array(x),
+                ..
+            } if matches!(x.kind(), TypeKind::Char)
+        ));
+
+        let char_map = HashMap::<String, char>::as_type_description();
+        assert!(
+            matches!(char_map.kind(), TypeKind::HashMap { key, value } if matches!(key.kind(), TypeKind::String) && matches!(value.kind(), TypeKind::Char))
+        );
     }
 
     #[test]
@@ -411,4 +1598,954 @@ mod tests {
             other => panic!("Expected HashMap, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_integer_keys() {
+        let map = HashMap::<u32, String>::as_type_description();
+        assert_eq!(map.name(), "Table of 'Integer => String'");
+
+        match map.kind() {
+            TypeKind::HashMap { key, value } => {
+                assert_eq!(**key, u32::as_type_description());
+                assert_eq!(**value, String::as_type_description());
+            }
+            other => panic!("Expected HashMap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_path_keys() {
+        let map = HashMap::<std::path::PathBuf, u8>::as_type_description();
+
+        match map.kind() {
+            TypeKind::HashMap { key, .. } => {
+                assert_eq!(**key, std::path::PathBuf::as_type_description());
+                assert_eq!(key.doc(), Some("A filesystem path"));
+            }
+            other => panic!("Expected HashMap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_kind_names() {
+        let desc = |kind| TypeDescription::new("Test".into(), kind, None);
+        let kinds = [
+            (TypeKind::Bool, "bool"),
+            (
+                TypeKind::Integer {
+                    sign: Sign::Signed,
+                    size: 8,
+                },
+                "integer",
+            ),
+            (TypeKind::Float { size: 32 }, "float"),
+            (TypeKind::String, "string"),
+            (TypeKind::Char, "char"),
+            (TypeKind::Bytes, "bytes"),
+            (TypeKind::Unit, "unit"),
+            (TypeKind::Any, "any"),
+            (TypeKind::Wrapped(Box::new(desc(TypeKind::Bool))), "wrapped"),
+            (TypeKind::Array(Box::new(desc(TypeKind::Bool))), "array"),
+            (TypeKind::Tuple(vec![desc(TypeKind::Bool)]), "tuple"),
+            (
+                TypeKind::HashMap {
+                    key: Box::new(desc(TypeKind::String)),
+                    value: Box::new(desc(TypeKind::Bool)),
+                },
+                "hashmap",
+            ),
+            (TypeKind::Struct(vec![]), "struct"),
+            (
+                TypeKind::Enum {
+                    tagging: TypeEnumKind::Untagged,
+                    variants: vec![],
+                    non_exhaustive: false,
+                },
+                "enum",
+            ),
+            (TypeKind::OneOf(vec![]), "one_of"),
+            (TypeKind::Ref("Test".into()), "ref"),
+        ];
+
+        for (kind, name) in kinds {
+            assert_eq!(kind.kind_name(), name);
+        }
+    }
+
+    #[test]
+    fn test_kind_categories() {
+        let categories = |desc: TypeDescription| {
+            (
+                desc.is_primitive(),
+                desc.is_composite(),
+                desc.is_collection(),
+            )
+        };
+
+        assert_eq!(
+            categories(bool::as_type_description()),
+            (true, false, false)
+        );
+        assert_eq!(categories(u8::as_type_description()), (true, false, false));
+        assert_eq!(categories(f32::as_type_description()), (true, false, false));
+        assert_eq!(
+            categories(String::as_type_description()),
+            (true, false, false)
+        );
+        assert_eq!(
+            categories(char::as_type_description()),
+            (true, false, false)
+        );
+        assert_eq!(
+            categories(crate::Bytes::as_type_description()),
+            (true, false, false)
+        );
+
+        assert_eq!(
+            categories(Vec::<u8>::as_type_description()),
+            (false, true, true)
+        );
+        assert_eq!(
+            categories(HashMap::<String, u8>::as_type_description()),
+            (false, true, true)
+        );
+        assert_eq!(
+            categories(<(u8, bool)>::as_type_description()),
+            (false, true, false)
+        );
+        assert_eq!(
+            categories(StructBuilder::new("Empty".into(), None).build()),
+            (false, true, false)
+        );
+        assert_eq!(
+            categories(Result::<u8, String>::as_type_description()),
+            (false, true, false)
+        );
+
+        assert_eq!(
+            categories(<()>::as_type_description()),
+            (false, false, false)
+        );
+        assert_eq!(
+            categories(Option::<u8>::as_type_description()),
+            (false, false, false)
+        );
+    }
+
+    #[test]
+    fn test_tuple_and_char_keys() {
+        let map = HashMap::<(String, u16), String>::as_type_description();
+
+        assert_eq!(
+            map.name(),
+            "Table of 'Tuple of ('String', 'Integer') => String'"
+        );
+        match map.kind() {
+            TypeKind::HashMap { key, value } => {
+                assert_eq!(**key, <(String, u16)>::as_type_description());
+                assert_eq!(**value, String::as_type_description());
+            }
+            other => panic!("Expected HashMap, got {:?}", other),
+        }
+
+        match BTreeMap::<char, bool>::as_type_description().kind() {
+            TypeKind::HashMap { key, .. } => assert_eq!(key.kind(), &TypeKind::Char),
+            other => panic!("Expected HashMap, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_btree_collections() {
+        let btree_config = BTreeMap::<String, Vec<BTreeMap<String, String>>>::as_type_description();
+
+        assert!(
+            matches!(btree_config.kind(), TypeKind::HashMap { value, .. } if matches!(value.kind(), TypeKind::Array(arr) if matches!(arr.kind(), TypeKind::HashMap { value, .. } if matches!(value.kind(), TypeKind::String))))
+        );
+        assert_eq!(
+            btree_config,
+            HashMap::<String, Vec<HashMap<String, String>>>::as_type_description()
+        );
+
+        let btree_set = BTreeSet::<u8>::as_type_description();
+        assert_eq!(btree_set.name(), "List of unique 'Integer'");
+        assert!(
+            matches!(btree_set.kind(), TypeKind::Array(x) if matches!(x.kind(), TypeKind::Integer { size: 8, sign: Sign::Unsigned }))
+        );
+    }
+
+    #[test]
+    fn test_sequence_collections() {
+        let set = HashSet::<String>::as_type_description();
+        assert_eq!(set.name(), "List of unique 'String'");
+        assert!(matches!(set.kind(), TypeKind::Array(x) if **x == String::as_type_description()));
+
+        let deque = VecDeque::<u64>::as_type_description();
+        assert_eq!(deque, Vec::<u64>::as_type_description());
+        assert_eq!(deque.name(), "Array of 'Integer's");
+    }
+
+    #[test]
+    fn test_struct_builder() {
+        let built = StructBuilder::new("Server".into(), Some("A server"))
+            .field("host", Some("The host"), String::as_type_description())
+            .optional_field("port", None, u16::as_type_description())
+            .build();
+
+        let manual = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "host",
+                    Some("The host"),
+                    String::as_type_description(),
+                    false,
+                ),
+                StructField::new("port", None, u16::as_type_description(), true),
+            ]),
+            Some("A server"),
+        );
+
+        assert_eq!(built, manual);
+    }
+
+    #[test]
+    fn test_constraints() {
+        let port = u16::as_type_description()
+            .with_constraints(vec![Constraint::Min(1), Constraint::Max(65535)]);
+        assert_eq!(
+            port.constraints(),
+            [Constraint::Min(1), Constraint::Max(65535)]
+        );
+        assert_ne!(port, u16::as_type_description());
+
+        let json = serde_json::to_value(&port).unwrap();
+        assert_eq!(
+            json["constraints"],
+            serde_json::json!([{ "Min": 1 }, { "Max": 65535 }])
+        );
+        assert_eq!(
+            serde_json::from_value::<TypeDescription>(json).unwrap(),
+            port
+        );
+
+        let plain = serde_json::to_value(u16::as_type_description()).unwrap();
+        assert!(plain.get("constraints").is_none());
+    }
+
+    #[test]
+    fn test_result() {
+        let result = Result::<u64, String>::as_type_description();
+        assert_eq!(result.name(), "Either 'Integer' or 'String'");
+
+        match result.kind() {
+            TypeKind::Enum {
+                tagging: TypeEnumKind::External,
+                variants,
+                ..
+            } => {
+                assert_eq!(variants[0].name(), "Ok");
+                assert!(
+                    matches!(variants[0].repr(), EnumVariantRepresentation::Wrapped(ok) if **ok == u64::as_type_description())
+                );
+                assert_eq!(variants[1].name(), "Err");
+                assert!(
+                    matches!(variants[1].repr(), EnumVariantRepresentation::Wrapped(err) if **err == String::as_type_description())
+                );
+            }
+            other => panic!("Expected externally tagged Enum, got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_to_json() {
+        let desc = StructBuilder::new("Server".into(), Some("A server"))
+            .field("ports", None, Vec::<u16>::as_type_description())
+            .build();
+
+        let pretty = desc.to_json_pretty().unwrap();
+        assert!(pretty.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<TypeDescription>(&pretty).unwrap(),
+            desc
+        );
+
+        let compact = desc.to_json_string().unwrap();
+        assert!(!compact.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<TypeDescription>(&compact).unwrap(),
+            desc
+        );
+
+        let mut written = Vec::new();
+        super::write_json(&desc, &mut written).unwrap();
+        assert_eq!(written, compact.as_bytes());
+
+        let mut written = Vec::new();
+        desc.write_json(&mut written).unwrap();
+        assert_eq!(written, compact.as_bytes());
+    }
+
+    #[test]
+    fn test_structurally_eq() {
+        let documented = StructBuilder::new("Server".into(), Some("A server"))
+            .field(
+                "port",
+                Some("The port to listen on"),
+                u16::as_type_description(),
+            )
+            .build();
+        let undocumented = StructBuilder::new("Server".into(), None)
+            .field("port", None, u16::as_type_description().without_docs())
+            .build();
+        let renamed = StructBuilder::new("Server".into(), Some("A server"))
+            .field("listen", None, u16::as_type_description())
+            .build();
+
+        assert_ne!(documented, undocumented);
+        assert!(documented.structurally_eq(&undocumented));
+        assert!(!documented.structurally_eq(&renamed));
+    }
+
+    #[test]
+    fn test_mutators() {
+        let mut desc = Vec::<u8>::as_type_description();
+
+        desc.set_name("Bytes".into());
+        assert_eq!(desc.name(), "Bytes");
+
+        desc.set_doc(Some("Some raw bytes"));
+        assert_eq!(desc.doc(), Some("Some raw bytes"));
+        desc.set_doc(None);
+        assert_eq!(desc.doc(), None);
+
+        if let TypeKind::Array(inner) = desc.kind_mut() {
+            inner.set_name("Byte".into());
+        }
+        assert!(matches!(desc.kind(), TypeKind::Array(inner) if inner.name() == "Byte"));
+
+        *desc.kind_mut() = TypeKind::String;
+        assert!(matches!(desc.kind(), TypeKind::String));
+    }
+
+    #[test]
+    fn test_clone_nested() {
+        let inner = StructBuilder::new("Tls".into(), Some("TLS settings"))
+            .field(
+                "certificate",
+                None,
+                std::path::PathBuf::as_type_description(),
+            )
+            .build();
+        let original = StructBuilder::new("Server".into(), None)
+            .field("tls", None, inner)
+            .field("ports", None, Vec::<u16>::as_type_description())
+            .build();
+
+        let mut copy = original.clone();
+        assert_eq!(copy, original);
+
+        copy.set_name("Client".into());
+        assert_ne!(copy, original);
+        assert_eq!(original.name(), "Server");
+    }
+
+    #[test]
+    fn test_dedup_descriptions() {
+        let mut set = HashSet::new();
+        set.insert(HashMap::<String, Vec<u32>>::as_type_description());
+        set.insert(HashMap::<String, Vec<u32>>::as_type_description());
+        assert_eq!(set.len(), 1);
+
+        set.insert(HashMap::<String, Vec<u64>>::as_type_description());
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_owned_docs() {
+        let language = String::from("en");
+        let desc = u16::as_type_description().with_doc(format!("The port ({language})"));
+        assert_eq!(desc.doc(), Some("The port (en)"));
+
+        let field = StructField::new("port", None, desc, false)
+            .with_name(format!("port_{language}"))
+            .with_doc(format!("Where to listen ({language})"));
+        assert_eq!(field.name(), "port_en");
+        assert_eq!(field.doc(), Some("Where to listen (en)"));
+
+        let variant =
+            EnumVariant::new("Low", None, EnumVariantRepresentation::String("low".into()))
+                .with_name(String::from("Niedrig"))
+                .with_doc(String::from("Wenig"));
+        assert_eq!(variant.name(), "Niedrig");
+        assert_eq!(variant.doc(), Some("Wenig"));
+    }
+
+    #[test]
+    fn test_without_docs() {
+        fn has_docs(value: &serde_json::Value) -> bool {
+            match value {
+                serde_json::Value::Object(map) => map
+                    .iter()
+                    .any(|(key, value)| (key == "doc" && !value.is_null()) || has_docs(value)),
+                serde_json::Value::Array(values) => values.iter().any(has_docs),
+                _ => false,
+            }
+        }
+
+        let desc = StructBuilder::new("Server".into(), Some("A server"))
+            .field(
+                "port",
+                Some("The port to listen on"),
+                u16::as_type_description(),
+            )
+            .optional_field(
+                "startup",
+                Some("Whether starting up worked"),
+                Result::<Vec<Duration>, HashMap<String, char>>::as_type_description(),
+            )
+            .build();
+        assert!(has_docs(&serde_json::to_value(&desc).unwrap()));
+
+        let stripped = desc.without_docs();
+        assert_eq!(stripped.name(), "Server");
+        assert!(!has_docs(&serde_json::to_value(&stripped).unwrap()));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let desc = StructBuilder::new("Server".into(), Some("A server"))
+            .field(
+                "port",
+                Some("The port to listen on"),
+                u16::as_type_description().with_constraints(vec![Constraint::Min(1)]),
+            )
+            .field(
+                "limits",
+                None,
+                HashMap::<String, (u64, f32)>::as_type_description(),
+            )
+            .optional_field(
+                "startup",
+                Some("Whether starting up worked"),
+                Result::<Duration, String>::as_type_description(),
+            )
+            .build();
+
+        let json = serde_json::to_string(&desc).unwrap();
+        let deserialized: TypeDescription = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, desc);
+    }
+
+    #[test]
+    fn test_one_of() {
+        let detailed = StructBuilder::new("Detailed".into(), None)
+            .field("version", None, String::as_type_description())
+            .build();
+        let desc = TypeDescription::one_of(
+            "Dependency".into(),
+            vec![String::as_type_description(), detailed.clone()],
+            Some("A version or a detailed dependency"),
+        );
+
+        assert_eq!(desc.doc(), Some("A version or a detailed dependency"));
+        assert!(
+            matches!(desc.kind(), TypeKind::OneOf(choices) if choices[..] == [String::as_type_description(), detailed])
+        );
+    }
+
+    #[test]
+    fn test_leaves() {
+        let port = StructBuilder::new("Port".into(), None)
+            .field("value", None, u16::as_type_description())
+            .field("tls", None, bool::as_type_description())
+            .build();
+        let server = StructBuilder::new("Server".into(), None)
+            .field("host", None, String::as_type_description())
+            .field(
+                "ports",
+                None,
+                TypeDescription::new("Ports".into(), TypeKind::Array(Box::new(port)), None),
+            )
+            .field(
+                "labels",
+                None,
+                HashMap::<String, f32>::as_type_description(),
+            )
+            .build();
+        let config = StructBuilder::new("Config".into(), None)
+            .field("server", None, server)
+            .build();
+
+        assert_eq!(
+            config.leaves(),
+            vec![
+                ("server.host".to_string(), &TypeKind::String),
+                (
+                    "server.ports[].value".to_string(),
+                    &TypeKind::Integer {
+                        sign: Sign::Unsigned,
+                        size: 16
+                    }
+                ),
+                ("server.ports[].tls".to_string(), &TypeKind::Bool),
+                ("server.labels{}".to_string(), &TypeKind::Float { size: 32 }),
+            ]
+        );
+        assert_eq!(
+            bool::as_type_description().leaves(),
+            vec![(String::new(), &TypeKind::Bool)]
+        );
+    }
+
+    #[test]
+    fn test_map_names() {
+        let choice = TypeDescription::new(
+            "Choice".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::External,
+                variants: vec![EnumVariant::new(
+                    "Many",
+                    None,
+                    EnumVariantRepresentation::Wrapped(Box::new(Vec::<u8>::as_type_description())),
+                )],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        let desc = StructBuilder::new("Config".into(), None)
+            .field("choice", None, choice)
+            .build()
+            .map_names(str::to_uppercase);
+
+        assert_eq!(desc.name(), "CONFIG");
+        let TypeKind::Struct(fields) = desc.kind() else {
+            panic!("Expected Struct, got {:?}", desc.kind());
+        };
+        assert_eq!(fields[0].name(), "CHOICE");
+        assert_eq!(fields[0].kind().name(), "CHOICE");
+
+        let TypeKind::Enum { variants, .. } = fields[0].kind().kind() else {
+            panic!("Expected Enum, got {:?}", fields[0].kind().kind());
+        };
+        assert_eq!(variants[0].name(), "MANY");
+        let EnumVariantRepresentation::Wrapped(inner) = variants[0].repr() else {
+            panic!("Expected a wrapped variant");
+        };
+        assert_eq!(inner.name(), "ARRAY OF 'INTEGER'S");
+        assert!(matches!(inner.kind(), TypeKind::Array(element) if element.name() == "INTEGER"));
+
+        let timeout = Option::<u8>::as_type_description().map_names(str::to_uppercase);
+        assert_eq!(timeout.name(), "AN OPTIONAL 'INTEGER'");
+        assert!(timeout.is_optional());
+    }
+
+    #[test]
+    fn test_metrics() {
+        let tls = StructBuilder::new("Tls".into(), None)
+            .field(
+                "certificate",
+                None,
+                std::path::PathBuf::as_type_description(),
+            )
+            .field("key", None, std::path::PathBuf::as_type_description())
+            .build();
+        let storage = TypeDescription::new(
+            "Storage".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::External,
+                variants: vec![
+                    EnumVariant::new(
+                        "Memory",
+                        None,
+                        EnumVariantRepresentation::String("Memory".into()),
+                    ),
+                    EnumVariant::new(
+                        "Disk",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(
+                            StructBuilder::new("Disk".into(), None)
+                                .field("path", None, std::path::PathBuf::as_type_description())
+                                .build(),
+                        )),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        let server = StructBuilder::new("Server".into(), None)
+            .field("tls", None, tls)
+            .field("ports", None, Vec::<u16>::as_type_description())
+            .field("storage", None, storage)
+            .field(
+                "labels",
+                None,
+                HashMap::<String, String>::as_type_description(),
+            )
+            .build();
+
+        assert_eq!(bool::as_type_description().depth(), 1);
+        assert_eq!(bool::as_type_description().field_count(), 0);
+
+        // Server -> storage -> Disk -> path
+        assert_eq!(server.depth(), 4);
+        assert_eq!(server.field_count(), 4 + 2 + 1);
+    }
+
+    #[test]
+    fn test_contains_kind() {
+        let is_float = |kind: &TypeKind| matches!(kind, TypeKind::Float { .. });
+
+        let with_float = StructBuilder::new("Limits".into(), None)
+            .field("names", None, Vec::<String>::as_type_description())
+            .field(
+                "ratios",
+                None,
+                HashMap::<String, Vec<f32>>::as_type_description(),
+            )
+            .build();
+        assert!(with_float.contains_kind(is_float));
+
+        let without_float = StructBuilder::new("Limits".into(), None)
+            .field("names", None, Vec::<String>::as_type_description())
+            .field(
+                "counts",
+                None,
+                HashMap::<String, Vec<u32>>::as_type_description(),
+            )
+            .build();
+        assert!(!without_float.contains_kind(is_float));
+        assert!(without_float.contains_kind(|kind| matches!(kind, TypeKind::Struct(_))));
+    }
+
+    #[test]
+    fn test_smart_pointers() {
+        assert_eq!(
+            Box::<u64>::as_type_description(),
+            u64::as_type_description()
+        );
+        assert_eq!(
+            std::rc::Rc::<String>::as_type_description(),
+            String::as_type_description()
+        );
+        assert_eq!(
+            std::sync::Arc::<Vec<bool>>::as_type_description(),
+            Vec::<bool>::as_type_description()
+        );
+    }
+
+    #[test]
+    fn test_struct_fields_and_enum_variants() {
+        let server = StructBuilder::new("Server".into(), None)
+            .field("port", None, u16::as_type_description())
+            .build();
+        let fields = server.struct_fields().unwrap();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name(), "port");
+        assert_eq!(server.enum_variants(), None);
+
+        let result = Result::<bool, String>::as_type_description();
+        let variants = result.enum_variants().unwrap();
+        assert_eq!(
+            variants.iter().map(EnumVariant::name).collect::<Vec<_>>(),
+            ["Ok", "Err"]
+        );
+        assert_eq!(result.struct_fields(), None);
+    }
+
+    #[test]
+    fn test_from_kind() {
+        assert_eq!(
+            TypeDescription::from(TypeKind::Bool),
+            bool::as_type_description()
+        );
+        assert_eq!(
+            TypeDescription::from(TypeKind::Integer {
+                sign: Sign::Unsigned,
+                size: 16
+            }),
+            u16::as_type_description()
+        );
+        assert_eq!(
+            TypeDescription::from(TypeKind::Array(Box::new(String::as_type_description()))),
+            Vec::<String>::as_type_description()
+        );
+
+        let point = TypeDescription::from(TypeKind::Struct(vec![]));
+        assert_eq!(point.name(), "Struct");
+        assert_eq!(point.doc(), None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let optional = TypeDescription::optional;
+        assert_eq!(
+            optional(optional(u8::as_type_description())).normalize(),
+            Option::<u8>::as_type_description()
+        );
+
+        let redundant = TypeDescription::new(
+            "Integer".into(),
+            TypeKind::Wrapped(Box::new(u8::as_type_description())),
+            None,
+        );
+        assert_eq!(redundant.normalize(), u8::as_type_description());
+
+        let port = TypeDescription::new(
+            "Port".into(),
+            TypeKind::Wrapped(Box::new(u16::as_type_description())),
+            Some("The port to listen on"),
+        );
+        assert_eq!(port.clone().normalize(), port);
+
+        let nested = TypeDescription::one_of(
+            "Setting".into(),
+            vec![
+                TypeDescription::one_of(
+                    "Either".into(),
+                    vec![bool::as_type_description(), String::as_type_description()],
+                    None,
+                ),
+                u8::as_type_description(),
+            ],
+            None,
+        );
+        assert_eq!(
+            nested.normalize(),
+            TypeDescription::one_of(
+                "Setting".into(),
+                vec![
+                    bool::as_type_description(),
+                    String::as_type_description(),
+                    u8::as_type_description()
+                ],
+                None,
+            )
+        );
+
+        let single =
+            TypeDescription::one_of("Only".into(), vec![char::as_type_description()], None);
+        assert_eq!(single.normalize(), char::as_type_description());
+
+        let config = StructBuilder::new("Config".into(), None)
+            .field(
+                "retries",
+                None,
+                optional(optional(u8::as_type_description())),
+            )
+            .build();
+        assert_eq!(
+            config.normalize(),
+            StructBuilder::new("Config".into(), None)
+                .field("retries", None, Option::<u8>::as_type_description())
+                .build()
+        );
+    }
+
+    #[test]
+    fn test_nested_option() {
+        assert_eq!(
+            Option::<Option<Option<u8>>>::as_type_description(),
+            Option::<u8>::as_type_description()
+        );
+        assert_eq!(
+            Option::<u8>::as_type_description().name(),
+            "An optional 'Integer'"
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        let unit = <()>::as_type_description();
+        assert_eq!(unit.kind(), &TypeKind::Unit);
+        assert_eq!(unit, TypeDescription::from(TypeKind::Unit));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(
+            std::num::Wrapping::<u32>::as_type_description(),
+            u32::as_type_description()
+        );
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(
+            std::cmp::Reverse::<Option<String>>::as_type_description(),
+            Option::<String>::as_type_description()
+        );
+    }
+
+    #[test]
+    fn test_cow() {
+        use std::borrow::Cow;
+
+        assert_eq!(
+            Cow::<'static, str>::as_type_description(),
+            String::as_type_description()
+        );
+        assert_eq!(
+            Cow::<'_, [u16]>::as_type_description(),
+            Vec::<u16>::as_type_description()
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_array() {
+        let array = <[u8; 4]>::as_type_description();
+        assert_eq!(array.name(), "Array of 'Integer's with length 4");
+        assert!(matches!(array.kind(), TypeKind::Array(x) if **x == u8::as_type_description()));
+    }
+
+    #[test]
+    fn test_tuples() {
+        let pair = <(String, u16)>::as_type_description();
+        assert_eq!(pair.name(), "Tuple of ('String', 'Integer')");
+        match pair.kind() {
+            TypeKind::Tuple(elements) => {
+                assert_eq!(elements.len(), 2);
+                assert_eq!(elements[0].kind(), &TypeKind::String);
+                assert_eq!(
+                    elements[1].kind(),
+                    &TypeKind::Integer {
+                        sign: Sign::Unsigned,
+                        size: 16
+                    }
+                );
+            }
+            other => panic!("Expected Tuple, got {:?}", other),
+        }
+
+        let triple = <(bool, f32, char)>::as_type_description();
+        assert!(
+            matches!(triple.kind(), TypeKind::Tuple(elements) if matches!(elements.as_slice(), [a, b, c] if a.kind() == &TypeKind::Bool && b.kind() == &TypeKind::Float { size: 32 } && c.kind() == &TypeKind::Char))
+        );
+
+        let pairs = Vec::<(String, u16)>::as_type_description();
+        assert!(matches!(pairs.kind(), TypeKind::Array(arr) if **arr == pair));
+    }
+
+    #[test]
+    fn test_duration() {
+        let duration = Duration::as_type_description();
+        assert_eq!(duration.name(), "Duration");
+
+        match duration.kind() {
+            TypeKind::Struct(fields) => {
+                assert_eq!(fields.len(), 2);
+                assert_eq!(fields[0].name(), "secs");
+                assert_eq!(*fields[0].kind(), u64::as_type_description());
+                assert_eq!(fields[1].name(), "nanos");
+                assert_eq!(*fields[1].kind(), u32::as_type_description());
+            }
+            other => panic!("Expected Struct, got {:?}", other),
+        }
+
+        assert!(
+            matches!(Option::<Duration>::as_type_description().kind(), TypeKind::Wrapped(inner) if **inner == duration)
+        );
+        assert!(
+            matches!(Vec::<Duration>::as_type_description().kind(), TypeKind::Array(inner) if **inner == duration)
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_url() {
+        let url = url::Url::as_type_description();
+        assert_eq!(url.name(), "String");
+        assert_eq!(url.doc(), Some("A URL"));
+        assert!(matches!(url.kind(), TypeKind::String));
+
+        assert!(
+            matches!(Vec::<url::Url>::as_type_description().kind(), TypeKind::Array(inner) if **inner == url)
+        );
+    }
+
+    #[cfg(feature = "humantime")]
+    #[test]
+    fn test_humantime() {
+        let duration = humantime::Duration::as_type_description();
+        assert_eq!(duration.name(), "String");
+        assert_eq!(
+            duration.doc(),
+            Some("A human-readable duration like '30s' or '5m'")
+        );
+        assert!(matches!(duration.kind(), TypeKind::String));
+        assert_ne!(duration, Duration::as_type_description());
+    }
+
+    #[cfg(feature = "bytesize")]
+    #[test]
+    fn test_bytesize() {
+        let size = bytesize::ByteSize::as_type_description();
+        assert_eq!(size.name(), "ByteSize");
+        assert_eq!(
+            size.doc(),
+            Some("A number of bytes, written with a unit like \"10 MB\" or \"4 KiB\"")
+        );
+        assert!(
+            matches!(size.kind(), TypeKind::Wrapped(inner) if **inner == String::as_type_description())
+        );
+    }
+
+    #[test]
+    fn test_ranges() {
+        let range = std::ops::Range::<u64>::as_type_description();
+        assert_eq!(range.name(), "Range of 'Integer's");
+
+        match range.kind() {
+            TypeKind::Struct(fields) => {
+                assert_eq!(fields.len(), 2);
+                assert_eq!(fields[0].name(), "start");
+                assert_eq!(*fields[0].kind(), u64::as_type_description());
+                assert_eq!(fields[1].name(), "end");
+                assert_eq!(fields[1].doc(), Some("The upper bound, which is excluded"));
+                assert_eq!(*fields[1].kind(), u64::as_type_description());
+            }
+            other => panic!("Expected Struct, got {:?}", other),
+        }
+
+        let inclusive = std::ops::RangeInclusive::<u8>::as_type_description();
+        assert!(
+            matches!(inclusive.kind(), TypeKind::Struct(fields) if fields[1].doc() == Some("The upper bound, which is included"))
+        );
+        assert_eq!(
+            serde_json::to_value(1u8..=5).unwrap(),
+            serde_json::json!({ "start": 1, "end": 5 })
+        );
+    }
+
+    #[test]
+    fn test_system_time() {
+        let time = std::time::SystemTime::as_type_description();
+        assert_eq!(time.name(), "SystemTime");
+
+        match time.kind() {
+            TypeKind::Struct(fields) => {
+                assert_eq!(fields[0].name(), "secs_since_epoch");
+                assert_eq!(fields[1].name(), "nanos_since_epoch");
+            }
+            other => panic!("Expected Struct, got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        let timestamp = chrono::DateTime::<chrono::Utc>::as_type_description();
+        assert!(matches!(timestamp.kind(), TypeKind::String));
+        assert_eq!(timestamp.doc(), Some("An RFC 3339 timestamp"));
+
+        for desc in [
+            chrono::NaiveDate::as_type_description(),
+            chrono::NaiveDateTime::as_type_description(),
+            chrono::NaiveTime::as_type_description(),
+        ] {
+            assert!(matches!(desc.kind(), TypeKind::String));
+            assert!(desc.doc().is_some());
+        }
+    }
 }
@@ -0,0 +1,186 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use crate::{StructField, TypeDescription, TypeKind};
+
+/// The reasons two [`struct@TypeDescription`]s cannot be merged
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum MergeError {
+    /// Only structs can be merged, but the type with this name is something else
+    NotAStruct(String),
+    /// Both structs have a field with this name, but of different kinds
+    ConflictingField {
+        /// The name of the field
+        name: String,
+        /// The field's type in the first struct
+        left: TypeDescription,
+        /// The field's type in the second struct
+        right: TypeDescription,
+    },
+}
+
+impl std::fmt::Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeError::NotAStruct(name) => write!(f, "'{name}' is not a struct"),
+            MergeError::ConflictingField { name, left, right } => write!(
+                f,
+                "the field `{name}` is both a '{}' and a '{}'",
+                left.name(),
+                right.name()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+fn into_fields(desc: TypeDescription) -> Result<Vec<StructField>, MergeError> {
+    match desc.kind {
+        TypeKind::Struct(fields) => Ok(fields),
+        _ => Err(MergeError::NotAStruct(desc.name)),
+    }
+}
+
+/// Combine two struct descriptions into one that has the fields of both
+///
+/// The name and documentation are taken from `a`, as are fields that appear in both structs. A
+/// field that appears in both has to be of the same kind in both, otherwise the merge fails.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, StructBuilder};
+///     use type_description::merge::merge;
+///
+///     let network = StructBuilder::new("Config".into(), None)
+///         .field("port", None, u16::as_type_description())
+///         .build();
+///     let logging = StructBuilder::new("Logging".into(), None)
+///         .field("verbose", None, bool::as_type_description())
+///         .build();
+///
+///     let config = merge(network, logging).unwrap();
+///     assert_eq!(config.name(), "Config");
+///     assert_eq!(config.struct_fields().unwrap().len(), 2);
+/// ```
+pub fn merge(a: TypeDescription, b: TypeDescription) -> Result<TypeDescription, MergeError> {
+    let TypeDescription {
+        name,
+        kind,
+        doc,
+        constraints,
+        examples,
+        optional,
+    } = a;
+    let mut fields = match kind {
+        TypeKind::Struct(fields) => fields,
+        _ => return Err(MergeError::NotAStruct(name)),
+    };
+
+    for field in into_fields(b)? {
+        match fields.iter().find(|other| other.name() == field.name()) {
+            Some(other) if other.kind().kind() != field.kind().kind() => {
+                return Err(MergeError::ConflictingField {
+                    name: field.name().to_string(),
+                    left: other.kind().clone(),
+                    right: field.kind().clone(),
+                })
+            }
+            Some(_) => (),
+            None => fields.push(field),
+        }
+    }
+
+    Ok(TypeDescription {
+        name,
+        kind: TypeKind::Struct(fields),
+        doc,
+        constraints,
+        examples,
+        optional,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, StructBuilder, TypeDescription};
+
+    use super::{merge, MergeError};
+
+    fn fragment(name: &str, fields: &[(&'static str, TypeDescription)]) -> TypeDescription {
+        fields
+            .iter()
+            .fold(
+                StructBuilder::new(name.into(), None),
+                |builder, (name, kind)| builder.field(name, None, kind.clone()),
+            )
+            .build()
+    }
+
+    #[test]
+    fn merge_unions_fields() {
+        let merged = merge(
+            fragment(
+                "Config",
+                &[
+                    ("host", String::as_type_description()),
+                    ("port", u16::as_type_description()),
+                ],
+            ),
+            fragment(
+                "Plugin",
+                &[
+                    ("port", u16::as_type_description()),
+                    ("verbose", bool::as_type_description()),
+                ],
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            merged,
+            fragment(
+                "Config",
+                &[
+                    ("host", String::as_type_description()),
+                    ("port", u16::as_type_description()),
+                    ("verbose", bool::as_type_description()),
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn merge_conflicting_fields() {
+        let err = merge(
+            fragment("Config", &[("port", u16::as_type_description())]),
+            fragment("Plugin", &[("port", String::as_type_description())]),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            MergeError::ConflictingField {
+                name: "port".into(),
+                left: u16::as_type_description(),
+                right: String::as_type_description(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "the field `port` is both a 'Integer' and a 'String'"
+        );
+    }
+
+    #[test]
+    fn merge_non_structs() {
+        assert_eq!(
+            merge(fragment("Config", &[]), Vec::<u8>::as_type_description()),
+            Err(MergeError::NotAStruct("Array of 'Integer's".into()))
+        );
+    }
+}
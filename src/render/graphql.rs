@@ -0,0 +1,368 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use super::{variant_content, Definitions};
+use crate::{EnumVariantRepresentation, StructField, TypeDescription, TypeKind};
+
+/// Turn a name into a valid GraphQL name, i.e. a letter or `_` followed by letters, digits and `_`
+fn graphql_name(name: &str) -> String {
+    let mut graphql_name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if !graphql_name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        graphql_name.insert(0, '_');
+    }
+    graphql_name
+}
+
+fn write_description(out: &mut String, indent: &str, doc: Option<&str>) {
+    let Some(doc) = doc else {
+        return;
+    };
+    let doc = doc.replace("\"\"\"", "\\\"\"\"");
+    if doc.contains('\n') {
+        writeln!(out, "{indent}\"\"\"").unwrap();
+        for line in doc.lines() {
+            writeln!(out, "{indent}{line}").unwrap();
+        }
+        writeln!(out, "{indent}\"\"\"").unwrap();
+    } else {
+        writeln!(out, "{indent}\"\"\"{doc}\"\"\"").unwrap();
+    }
+}
+
+fn union_definition(name: &str, doc: Option<&str>, members: &[String]) -> String {
+    let mut definition = String::new();
+    write_description(&mut definition, "", doc);
+    write!(definition, "union {name} = {}", members.join(" | ")).unwrap();
+    definition
+}
+
+#[derive(Default)]
+struct Sdl {
+    definitions: Definitions,
+    /// The custom scalars that are used
+    scalars: BTreeSet<&'static str>,
+}
+
+impl Sdl {
+    fn scalar(&mut self, name: &'static str) -> String {
+        self.scalars.insert(name);
+        format!("{name}!")
+    }
+
+    fn define_object(&mut self, name: &str, doc: Option<&str>, fields: &[StructField]) {
+        let Some(index) = self.definitions.reserve(name) else {
+            return;
+        };
+
+        let mut definition = String::new();
+        write_description(&mut definition, "", doc);
+        writeln!(definition, "type {name} {{").unwrap();
+        for field in fields {
+            let mut ty = self.reference(field.kind());
+            if field.optional() {
+                ty = ty.trim_end_matches('!').to_string();
+            }
+            write_description(&mut definition, "  ", field.doc().or(field.kind().doc()));
+            writeln!(definition, "  {}: {ty}", graphql_name(field.name())).unwrap();
+        }
+        definition.push('}');
+        self.definitions.define(index, definition);
+    }
+
+    /// Define an object type with a single field, for a union member that is no object itself
+    fn define_value_object(&mut self, name: &str, doc: Option<&str>, field: &str, ty: String) {
+        if let Some(index) = self.definitions.reserve(name) {
+            let mut definition = String::new();
+            write_description(&mut definition, "", doc);
+            write!(definition, "type {name} {{\n  {field}: {ty}\n}}").unwrap();
+            self.definitions.define(index, definition);
+        }
+    }
+
+    /// Get the GraphQL type of a description, defining it and the types it uses on the way
+    fn reference(&mut self, desc: &TypeDescription) -> String {
+        let name = graphql_name(desc.name());
+        match desc.kind() {
+            TypeKind::Bool => "Boolean!".to_string(),
+            TypeKind::Integer { .. } => "Int!".to_string(),
+            TypeKind::Float { .. } => "Float!".to_string(),
+            TypeKind::String | TypeKind::Char => "String!".to_string(),
+            TypeKind::Bytes => self.scalar("Bytes"),
+            TypeKind::Unit | TypeKind::Any | TypeKind::Tuple(_) | TypeKind::HashMap { .. } => {
+                self.scalar("JSON")
+            }
+            TypeKind::Ref(name) => format!("{}!", graphql_name(name)),
+            TypeKind::Wrapped(inner) if desc.is_optional() => {
+                self.reference(inner).trim_end_matches('!').to_string()
+            }
+            TypeKind::Wrapped(inner) => self.reference(inner),
+            TypeKind::Array(inner) => format!("[{}]!", self.reference(inner)),
+            TypeKind::Struct(fields) => {
+                self.define_object(&name, desc.doc(), fields);
+                format!("{name}!")
+            }
+            TypeKind::OneOf(choices)
+                if choices
+                    .iter()
+                    .all(|choice| matches!(choice.kind(), TypeKind::Struct(_))) =>
+            {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let members = choices
+                        .iter()
+                        .map(|choice| self.reference(choice).trim_end_matches('!').to_string())
+                        .collect::<Vec<_>>();
+                    self.definitions
+                        .define(index, union_definition(&name, desc.doc(), &members));
+                }
+                format!("{name}!")
+            }
+            TypeKind::OneOf(_) => self.scalar("JSON"),
+            TypeKind::Enum { variants, .. }
+                if variants.iter().all(|variant| {
+                    matches!(variant.repr(), EnumVariantRepresentation::String(_))
+                }) =>
+            {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let mut definition = String::new();
+                    write_description(&mut definition, "", desc.doc());
+                    writeln!(definition, "enum {name} {{").unwrap();
+                    for variant in variants {
+                        if let EnumVariantRepresentation::String(repr) = variant.repr() {
+                            write_description(&mut definition, "  ", variant.doc());
+                            writeln!(definition, "  {}", graphql_name(repr)).unwrap();
+                        }
+                    }
+                    definition.push('}');
+                    self.definitions.define(index, definition);
+                }
+                format!("{name}!")
+            }
+            TypeKind::Enum { variants, .. } => {
+                let Some(index) = self.definitions.reserve(&name) else {
+                    return format!("{name}!");
+                };
+
+                let members = variants
+                    .iter()
+                    .map(|variant| {
+                        let member = format!("{name}{}", graphql_name(variant.name()));
+                        match variant_content(variant) {
+                            // Objects need at least one field, so unit variants get a placeholder
+                            None => {
+                                self.define_value_object(
+                                    &member,
+                                    variant.doc(),
+                                    "_",
+                                    "Boolean".to_string(),
+                                );
+                                member
+                            }
+                            Some(content) => match content.kind() {
+                                TypeKind::Struct(fields) if content.name() == variant.name() => {
+                                    self.define_object(&member, variant.doc(), fields);
+                                    member
+                                }
+                                TypeKind::Struct(_) | TypeKind::Ref(_) => {
+                                    self.reference(content).trim_end_matches('!').to_string()
+                                }
+                                _ => {
+                                    let ty = self.reference(content);
+                                    self.define_value_object(&member, variant.doc(), "value", ty);
+                                    member
+                                }
+                            },
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                self.definitions
+                    .define(index, union_definition(&name, desc.doc(), &members));
+                format!("{name}!")
+            }
+        }
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to a [GraphQL](https://graphql.org/) schema definition
+///
+/// Structs become object types and enums whose variants are all plain strings become GraphQL
+/// enums, other enums become unions of one object type per variant. Primitives map to the
+/// built-in scalars where possible, values without a GraphQL equivalent, like maps and tuples,
+/// use a custom `JSON` scalar, and binary data a custom `Bytes` scalar. Documentation is carried
+/// over as descriptions.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, StructBuilder};
+///     use type_description::render::to_graphql_sdl;
+///
+///     let server = StructBuilder::new("Server".into(), None)
+///         .field("port", None, u16::as_type_description())
+///         .build()
+///         .without_docs();
+///
+///     assert_eq!(to_graphql_sdl(&server), "type Server {\n  port: Int!\n}\n");
+/// ```
+pub fn to_graphql_sdl(desc: &TypeDescription) -> String {
+    let mut sdl = Sdl::default();
+    sdl.reference(desc);
+
+    sdl.scalars
+        .iter()
+        .map(|scalar| format!("scalar {scalar}"))
+        .chain(sdl.definitions)
+        .map(|definition| definition + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructBuilder, StructField,
+        TypeDescription, TypeEnumKind, TypeKind,
+    };
+
+    use super::to_graphql_sdl;
+
+    #[test]
+    fn graphql_struct_and_enum() {
+        let level = TypeDescription::new(
+            "Level".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Untagged,
+                variants: vec![
+                    EnumVariant::new(
+                        "Low",
+                        Some("Only errors"),
+                        EnumVariantRepresentation::String("low".into()),
+                    ),
+                    EnumVariant::new(
+                        "High",
+                        None,
+                        EnumVariantRepresentation::String("high".into()),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            Some("How much to log"),
+        );
+        let server = StructBuilder::new("Server".into(), Some("A server\n\nListens on a port"))
+            .field(
+                "port",
+                Some("The port to listen on"),
+                u16::as_type_description().without_docs(),
+            )
+            .field(
+                "tags",
+                None,
+                Vec::<String>::as_type_description().without_docs(),
+            )
+            .optional_field("timeout", None, f64::as_type_description().without_docs())
+            .field(
+                "key",
+                None,
+                Option::<Vec<u8>>::as_type_description().without_docs(),
+            )
+            .field("log_level", None, level)
+            .build();
+
+        assert_eq!(
+            to_graphql_sdl(&server),
+            r#""""
+A server
+
+Listens on a port
+"""
+type Server {
+  """The port to listen on"""
+  port: Int!
+  tags: [String!]!
+  timeout: Float
+  key: [Int!]
+  """How much to log"""
+  log_level: Level!
+}
+
+"""How much to log"""
+enum Level {
+  """Only errors"""
+  low
+  high
+}
+"#
+        );
+    }
+
+    #[test]
+    fn graphql_union() {
+        let circle = TypeDescription::new(
+            "Circle".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "radius",
+                None,
+                f32::as_type_description().without_docs(),
+                false,
+            )]),
+            None,
+        );
+        let shape = TypeDescription::new(
+            "Shape".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Tagged("type".into()),
+                variants: vec![
+                    EnumVariant::new(
+                        "Circle",
+                        Some("A round shape"),
+                        EnumVariantRepresentation::Wrapped(Box::new(circle)),
+                    ),
+                    EnumVariant::new(
+                        "Square",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(TypeDescription::new(
+                            "Square".into(),
+                            TypeKind::Wrapped(Box::new(u32::as_type_description().without_docs())),
+                            None,
+                        ))),
+                    ),
+                    EnumVariant::new(
+                        "Empty",
+                        None,
+                        EnumVariantRepresentation::String("Empty".into()),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            Some("A \"\"\"shape\"\"\""),
+        );
+
+        assert_eq!(
+            to_graphql_sdl(&shape),
+            r#""""A \"""shape\""""""
+union Shape = ShapeCircle | ShapeSquare | ShapeEmpty
+
+"""A round shape"""
+type ShapeCircle {
+  radius: Float!
+}
+
+type ShapeSquare {
+  value: Int!
+}
+
+type ShapeEmpty {
+  _: Boolean
+}
+"#
+        );
+    }
+}
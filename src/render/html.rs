@@ -0,0 +1,258 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use crate::{EnumVariantRepresentation, TypeDescription, TypeEnumKind, TypeKind};
+
+use super::describe_constraints;
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn render_doc(out: &mut String, doc: Option<&str>) {
+    if let Some(doc) = doc {
+        out.push_str(&format!("<p class=\"doc\">{}</p>", escape(doc)));
+    }
+}
+
+fn render_node(out: &mut String, desc: &TypeDescription) {
+    out.push_str(&format!(
+        "<section><header><span class=\"name\">{}</span> <span class=\"kind\">{}</span></header>",
+        escape(desc.name()),
+        desc.kind().kind_name()
+    ));
+    render_doc(out, desc.doc());
+
+    if !desc.constraints().is_empty() {
+        out.push_str(&format!(
+            "<p class=\"constraints\">{}</p>",
+            escape(&describe_constraints(desc))
+        ));
+    }
+
+    if !desc.examples().is_empty() {
+        out.push_str("<p class=\"examples\">Examples: ");
+        for (idx, example) in desc.examples().iter().enumerate() {
+            if idx > 0 {
+                out.push_str(", ");
+            }
+            out.push_str(&format!("<code>{}</code>", escape(example)));
+        }
+        out.push_str("</p>");
+    }
+
+    match desc.kind() {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Ref(_) => (),
+        TypeKind::Unit => out.push_str("<p class=\"unit\">No value</p>"),
+        TypeKind::Any => out.push_str("<p class=\"any\">Any value</p>"),
+        TypeKind::Wrapped(inner) => render_node(out, inner),
+        TypeKind::Array(inner) => {
+            out.push_str("<ul><li>");
+            render_node(out, inner);
+            out.push_str("</li></ul>");
+        }
+        TypeKind::Tuple(elements) => {
+            out.push_str("<ol>");
+            for element in elements {
+                out.push_str("<li>");
+                render_node(out, element);
+                out.push_str("</li>");
+            }
+            out.push_str("</ol>");
+        }
+        TypeKind::OneOf(choices) => {
+            out.push_str("<ul class=\"one-of\">");
+            for choice in choices {
+                out.push_str("<li>");
+                render_node(out, choice);
+                out.push_str("</li>");
+            }
+            out.push_str("</ul>");
+        }
+        TypeKind::HashMap { key, value } => {
+            out.push_str("<ul><li><span class=\"key\">Key</span>");
+            render_node(out, key);
+            out.push_str("</li><li><span class=\"value\">Value</span>");
+            render_node(out, value);
+            out.push_str("</li></ul>");
+        }
+        TypeKind::Struct(fields) => {
+            out.push_str("<dl>");
+            for field in fields {
+                out.push_str(&format!("<dt><code>{}</code>", escape(field.label())));
+                if field.optional() {
+                    out.push_str(" <span class=\"optional\">optional</span>");
+                }
+                out.push_str("</dt><dd>");
+                render_doc(out, field.doc());
+                render_node(out, field.kind());
+                out.push_str("</dd>");
+            }
+            out.push_str("</dl>");
+        }
+        TypeKind::Enum {
+            tagging: tag_kind,
+            variants,
+            non_exhaustive,
+        } => {
+            match tag_kind {
+                TypeEnumKind::Tagged(tag) => out.push_str(&format!(
+                    "<p class=\"tagging\">Tagged with field <code>{}</code></p>",
+                    escape(tag)
+                )),
+                TypeEnumKind::Adjacent { tag, content } => out.push_str(&format!(
+                    "<p class=\"tagging\">Tagged with field <code>{}</code>, content in field \
+                     <code>{}</code></p>",
+                    escape(tag),
+                    escape(content)
+                )),
+                TypeEnumKind::Untagged => out.push_str("<p class=\"tagging\">Untagged</p>"),
+                TypeEnumKind::External => {
+                    out.push_str("<p class=\"tagging\">Externally tagged</p>")
+                }
+            }
+            if *non_exhaustive {
+                out.push_str("<p class=\"non-exhaustive\">More variants may be added</p>");
+            }
+            out.push_str("<dl>");
+            for variant in variants {
+                out.push_str(&format!(
+                    "<dt><code>{}</code></dt><dd>",
+                    escape(variant.label())
+                ));
+                render_doc(out, variant.doc());
+                if let EnumVariantRepresentation::Wrapped(inner) = variant.repr() {
+                    render_node(out, inner);
+                }
+                out.push_str("</dd>");
+            }
+            out.push_str("</dl>");
+        }
+    }
+
+    out.push_str("</section>");
+}
+
+/// Render a [`struct@TypeDescription`] to an HTML fragment
+///
+/// Every type becomes a `<section>`, struct fields and enum variants are rendered as definition
+/// lists and the elements of arrays and tables as lists. The names, kinds and docs carry the
+/// `name`, `kind` and `doc` classes for styling, with the kinds written as by
+/// [`TypeKind::kind_name`]. All text is escaped, so the fragment can be
+/// embedded into a page as is.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::render::to_html;
+///
+///     let html = to_html(&bool::as_type_description());
+///
+///     assert_eq!(
+///         html,
+///         concat!(
+///             "<div class=\"type-description\"><section>",
+///             "<header><span class=\"name\">Boolean</span> <span class=\"kind\">bool</span></header>",
+///             "<p class=\"doc\">A boolean</p>",
+///             "</section></div>"
+///         )
+///     );
+/// ```
+pub fn to_html(desc: &TypeDescription) -> String {
+    let mut out = String::from("<div class=\"type-description\">");
+    render_node(&mut out, desc);
+    out.push_str("</div>");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, StructBuilder, TypeDescription, TypeKind};
+
+    use super::to_html;
+
+    /// Check that every opened tag is closed again in the right order
+    fn assert_well_formed(html: &str) {
+        let mut open = vec![];
+        let mut rest = html;
+        while let Some(start) = rest.find('<') {
+            let end = rest[start..].find('>').expect("Unterminated tag") + start;
+            let tag = &rest[start + 1..end];
+            match tag.strip_prefix('/') {
+                Some(name) => assert_eq!(open.pop(), Some(name), "Mismatched </{name}>"),
+                None => open.push(tag.split(' ').next().unwrap()),
+            }
+            rest = &rest[end + 1..];
+        }
+        assert!(open.is_empty(), "Unclosed tags: {open:?}");
+    }
+
+    #[test]
+    fn render_struct_fields() {
+        let desc = StructBuilder::new("Server".into(), Some("A server"))
+            .field("port", Some("The port"), u16::as_type_description())
+            .optional_field("hosts", None, Vec::<String>::as_type_description())
+            .build();
+
+        let html = to_html(&desc);
+        assert_well_formed(&html);
+        assert_eq!(
+            html,
+            concat!(
+                "<div class=\"type-description\"><section>",
+                "<header><span class=\"name\">Server</span> <span class=\"kind\">struct</span></header>",
+                "<p class=\"doc\">A server</p>",
+                "<dl>",
+                "<dt><code>port</code></dt><dd><p class=\"doc\">The port</p><section>",
+                "<header><span class=\"name\">Integer</span> <span class=\"kind\">integer</span></header>",
+                "<p class=\"doc\">An unsigned integer with 16 bits</p></section></dd>",
+                "<dt><code>hosts</code> <span class=\"optional\">optional</span></dt><dd><section>",
+                "<header><span class=\"name\">Array of &#39;String&#39;s</span> <span class=\"kind\">array</span></header>",
+                "<ul><li><section>",
+                "<header><span class=\"name\">String</span> <span class=\"kind\">string</span></header>",
+                "<p class=\"doc\">An UTF-8 string</p></section></li></ul>",
+                "</section></dd>",
+                "</dl>",
+                "</section></div>"
+            )
+        );
+    }
+
+    #[test]
+    fn render_escaped_docs() {
+        let desc = TypeDescription::new(
+            "Vec<u8>".into(),
+            TypeKind::Wrapped(Box::new(
+                std::collections::HashMap::<String, u8>::as_type_description(),
+            )),
+            Some("Values < 10 & > 2"),
+        );
+
+        let html = to_html(&desc);
+        assert_well_formed(&html);
+        assert!(html.contains("<span class=\"name\">Vec&lt;u8&gt;</span>"));
+        assert!(html.contains("<p class=\"doc\">Values &lt; 10 &amp; &gt; 2</p>"));
+        assert!(!html.contains("Values < 10"));
+    }
+}
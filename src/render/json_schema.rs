@@ -0,0 +1,1010 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use serde_json::{json, Map, Value};
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+use super::RenderOptions;
+use crate::visit::{walk, TypeDescriptionVisitor};
+use crate::{
+    Constraint, EnumVariant, EnumVariantRepresentation, Sign, TypeDescription, TypeEnumKind,
+    TypeKind,
+};
+
+/// The names of the types that are referred to by a [`TypeKind::Ref`]
+type Refs = BTreeSet<String>;
+
+/// The flavor of JSON Schema to produce
+#[derive(PartialEq, Eq)]
+enum Dialect {
+    /// JSON Schema draft 2020-12
+    JsonSchema,
+    /// The schema objects of OpenAPI 3.0, an extended subset of an older JSON Schema draft
+    OpenApi,
+}
+
+struct Context {
+    refs: Refs,
+    dialect: Dialect,
+    /// The referred to types that already got their `$anchor`, as every anchor may only exist once
+    anchored: RefCell<BTreeSet<String>>,
+}
+
+impl Context {
+    fn new(desc: &TypeDescription, dialect: Dialect) -> Self {
+        let mut refs = CollectRefs::default();
+        walk(desc, &mut refs);
+        Context {
+            refs: refs.0,
+            dialect,
+            anchored: RefCell::default(),
+        }
+    }
+
+    /// A schema that only allows the given value
+    fn constant(&self, value: impl Into<Value>) -> Value {
+        match self.dialect {
+            Dialect::JsonSchema => json!({ "const": value.into() }),
+            Dialect::OpenApi => json!({ "enum": [value.into()] }),
+        }
+    }
+
+    /// A schema that allows `null` next to the values of the given schema
+    fn nullable(&self, mut schema: Value) -> Value {
+        match (&self.dialect, &mut schema) {
+            (Dialect::JsonSchema, _) => json!({ "anyOf": [schema, { "type": "null" }] }),
+            (Dialect::OpenApi, Value::Object(obj)) => {
+                obj.insert("nullable".into(), Value::Bool(true));
+                schema
+            }
+            (Dialect::OpenApi, _) => schema,
+        }
+    }
+}
+
+#[derive(Default)]
+struct CollectRefs(Refs);
+
+impl TypeDescriptionVisitor for CollectRefs {
+    fn visit_ref(&mut self, _desc: &TypeDescription, name: &str) {
+        self.0.insert(name.to_string());
+    }
+}
+
+/// Turn a type name into a valid `$anchor`, i.e. a letter or `_` followed by letters, digits,
+/// `-`, `.` and `_`
+fn anchor(name: &str) -> String {
+    let mut anchor = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if !anchor.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        anchor.insert(0, '_');
+    }
+    anchor
+}
+
+fn with_description(mut schema: Value, doc: Option<&str>) -> Value {
+    if let (Some(doc), Value::Object(obj)) = (doc, &mut schema) {
+        obj.insert("description".into(), Value::String(doc.to_string()));
+    }
+    schema
+}
+
+fn with_constraints(mut schema: Value, desc: &TypeDescription) -> Value {
+    let Value::Object(obj) = &mut schema else {
+        return schema;
+    };
+
+    let (min_len, max_len) = match desc.kind() {
+        TypeKind::Array(_) | TypeKind::Tuple(_) => ("minItems", "maxItems"),
+        TypeKind::HashMap { .. } | TypeKind::Struct(_) => ("minProperties", "maxProperties"),
+        _ => ("minLength", "maxLength"),
+    };
+
+    for constraint in desc.constraints() {
+        let (key, value) = match constraint {
+            Constraint::Min(min) => ("minimum", json!(min)),
+            Constraint::Max(max) => ("maximum", json!(max)),
+            Constraint::MinLen(min) => (min_len, json!(min)),
+            Constraint::MaxLen(max) => (max_len, json!(max)),
+            Constraint::Pattern(pattern) => ("pattern", json!(pattern)),
+        };
+        obj.insert(key.into(), value);
+    }
+
+    schema
+}
+
+/// Add the examples of a type, as JSON values where they are written like one
+///
+/// OpenAPI 3.0 only has room for a single `example`, so only the first one is kept there.
+fn with_examples(mut schema: Value, desc: &TypeDescription, ctx: &Context) -> Value {
+    let Value::Object(obj) = &mut schema else {
+        return schema;
+    };
+    if desc.examples().is_empty() {
+        return schema;
+    }
+
+    let mut kind = desc.kind();
+    while let TypeKind::Wrapped(inner) = kind {
+        kind = inner.kind();
+    }
+    let examples = desc.examples().iter().map(|example| match kind {
+        TypeKind::String | TypeKind::Char | TypeKind::Bytes => Value::String(example.to_string()),
+        _ => serde_json::from_str(example).unwrap_or_else(|_| Value::String(example.to_string())),
+    });
+    match ctx.dialect {
+        Dialect::JsonSchema => {
+            obj.insert("examples".into(), examples.collect());
+        }
+        Dialect::OpenApi => {
+            obj.extend(examples.take(1).map(|example| ("example".into(), example)));
+        }
+    }
+
+    schema
+}
+
+fn variant_to_schema(variant: &EnumVariant, tag_kind: &TypeEnumKind, ctx: &Context) -> Value {
+    let schema = match (tag_kind, variant.repr()) {
+        (
+            TypeEnumKind::Untagged | TypeEnumKind::External,
+            EnumVariantRepresentation::String(repr),
+        ) => ctx.constant(repr.as_ref()),
+        (TypeEnumKind::External, EnumVariantRepresentation::Wrapped(inner)) => json!({
+            "type": "object",
+            "properties": { variant.name(): desc_to_schema(inner, ctx) },
+            "required": [variant.name()],
+            "additionalProperties": false,
+        }),
+        (TypeEnumKind::Untagged, EnumVariantRepresentation::Wrapped(inner)) => {
+            desc_to_schema(inner, ctx)
+        }
+        (TypeEnumKind::Adjacent { tag, .. }, EnumVariantRepresentation::String(repr)) => json!({
+            "type": "object",
+            "properties": { tag.as_ref(): ctx.constant(repr.as_ref()) },
+            "required": [tag],
+        }),
+        (TypeEnumKind::Adjacent { tag, content }, EnumVariantRepresentation::Wrapped(inner)) => {
+            json!({
+                "type": "object",
+                "properties": {
+                    tag.as_ref(): ctx.constant(variant.name()),
+                    content.as_ref(): desc_to_schema(inner, ctx),
+                },
+                "required": [tag, content],
+            })
+        }
+        (TypeEnumKind::Tagged(tag), EnumVariantRepresentation::String(repr)) => json!({
+            "type": "object",
+            "properties": { tag.as_ref(): ctx.constant(repr.as_ref()) },
+            "required": [tag],
+        }),
+        (TypeEnumKind::Tagged(tag), EnumVariantRepresentation::Wrapped(inner)) => {
+            let mut schema = desc_to_schema(inner, ctx);
+            match schema.get_mut("properties").and_then(Value::as_object_mut) {
+                Some(properties) => {
+                    properties.insert(tag.to_string(), ctx.constant(variant.name()));
+                    if let Some(Value::Array(required)) = schema.get_mut("required") {
+                        required.insert(0, Value::String(tag.to_string()));
+                    }
+                    schema
+                }
+                None => json!({
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": { tag.as_ref(): ctx.constant(variant.name()) },
+                            "required": [tag],
+                        },
+                        schema,
+                    ]
+                }),
+            }
+        }
+    };
+
+    with_description(schema, variant.doc())
+}
+
+fn desc_to_schema(desc: &TypeDescription, ctx: &Context) -> Value {
+    let openapi = ctx.dialect == Dialect::OpenApi;
+    let anchored =
+        !openapi && ctx.refs.contains(desc.name()) && !matches!(desc.kind(), TypeKind::Ref(_));
+    // Further occurrences of a type that is already anchored refer to the first one
+    if anchored && !ctx.anchored.borrow_mut().insert(desc.name().to_string()) {
+        return json!({ "$ref": format!("#{}", anchor(desc.name())) });
+    }
+
+    let schema = match desc.kind() {
+        TypeKind::Bool => json!({ "type": "boolean" }),
+        TypeKind::Integer { sign, size } => {
+            let mut schema = json!({ "type": "integer" });
+            if *sign == Sign::Unsigned {
+                schema["minimum"] = json!(0);
+            }
+            // The formats are signed, so an unsigned 32 bit integer only fits into an `int64`
+            let fits_int32 = match sign {
+                Sign::Signed => *size <= 32,
+                Sign::Unsigned => *size < 32,
+            };
+            if openapi && *size <= 64 {
+                schema["format"] = json!(if fits_int32 { "int32" } else { "int64" });
+            }
+            schema
+        }
+        TypeKind::Float { size } if openapi => json!({
+            "type": "number",
+            "format": if *size <= 32 { "float" } else { "double" },
+        }),
+        TypeKind::Float { .. } => json!({ "type": "number" }),
+        TypeKind::String => json!({ "type": "string" }),
+        TypeKind::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        TypeKind::Bytes if openapi => json!({ "type": "string", "format": "byte" }),
+        TypeKind::Bytes => json!({ "type": "string", "contentEncoding": "base64" }),
+        TypeKind::Unit if openapi => json!({ "nullable": true, "enum": [null] }),
+        TypeKind::Unit => json!({ "type": "null" }),
+        TypeKind::Any => json!({}),
+        TypeKind::Ref(name) if openapi => {
+            json!({ "$ref": format!("#/components/schemas/{}", anchor(name)) })
+        }
+        TypeKind::Ref(name) => json!({ "$ref": format!("#{}", anchor(name)) }),
+        TypeKind::Wrapped(inner) if desc.is_optional() => ctx.nullable(desc_to_schema(inner, ctx)),
+        TypeKind::Wrapped(inner) => desc_to_schema(inner, ctx),
+        TypeKind::Array(inner) => json!({ "type": "array", "items": desc_to_schema(inner, ctx) }),
+        TypeKind::Tuple(elements) => {
+            let elements = elements
+                .iter()
+                .map(|element| desc_to_schema(element, ctx))
+                .collect::<Vec<_>>();
+            let len = elements.len();
+            // OpenAPI 3.0 cannot give every position its own schema
+            let (key, items) = if openapi {
+                ("items", json!({ "oneOf": elements }))
+            } else {
+                ("prefixItems", Value::Array(elements))
+            };
+            json!({
+                "type": "array",
+                key: items,
+                "minItems": len,
+                "maxItems": len,
+            })
+        }
+        TypeKind::OneOf(choices) => json!({
+            "oneOf": choices.iter().map(|element| desc_to_schema(element, ctx)).collect::<Vec<_>>(),
+        }),
+        TypeKind::HashMap { value, .. } => json!({
+            "type": "object",
+            "additionalProperties": desc_to_schema(value, ctx),
+        }),
+        TypeKind::Struct(fields) => {
+            let properties = fields
+                .iter()
+                .map(|field| {
+                    let mut schema = desc_to_schema(field.kind(), ctx);
+                    // serde writes a `None` as `null` unless it skips it, so optional fields
+                    // accept both
+                    if field.optional() && !field.kind().is_optional() {
+                        schema = ctx.nullable(schema);
+                    }
+                    (
+                        field.name().to_string(),
+                        with_description(schema, field.doc()),
+                    )
+                })
+                .collect::<Map<_, _>>();
+            let required = fields
+                .iter()
+                .filter(|field| !field.optional())
+                .map(|field| field.name())
+                .collect::<Vec<_>>();
+
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+            })
+        }
+        TypeKind::Enum {
+            tagging: TypeEnumKind::Untagged | TypeEnumKind::External,
+            variants,
+            ..
+        } if variants
+            .iter()
+            .all(|variant| matches!(variant.repr(), EnumVariantRepresentation::String(_))) =>
+        {
+            json!({
+                "enum": variants
+                    .iter()
+                    .map(|variant| match variant.repr() {
+                        EnumVariantRepresentation::String(repr) => Value::String(repr.to_string()),
+                        EnumVariantRepresentation::Wrapped(_) => unreachable!(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+        }
+        TypeKind::Enum {
+            tagging: tag_kind,
+            variants,
+            ..
+        } => {
+            let schemas = variants
+                .iter()
+                .map(|variant| variant_to_schema(variant, tag_kind, ctx))
+                .collect::<Vec<_>>();
+            match tag_kind {
+                // An OpenAPI discriminator only works with `oneOf` members that are references to
+                // component schemas, while the variants are written inline
+                _ if openapi => json!({ "oneOf": schemas }),
+                TypeEnumKind::Tagged(tag) | TypeEnumKind::Adjacent { tag, .. } => {
+                    // The mapping points into the `oneOf` relative to this schema for now, see
+                    // `resolve_mappings`
+                    let mapping = variants
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, variant)| {
+                            let value = match variant.repr() {
+                                EnumVariantRepresentation::String(repr) => repr.to_string(),
+                                EnumVariantRepresentation::Wrapped(_) => variant.name().to_string(),
+                            };
+                            (value, Value::String(format!("oneOf/{idx}")))
+                        })
+                        .collect::<serde_json::Map<_, _>>();
+                    json!({
+                        "oneOf": schemas,
+                        "discriminator": { "propertyName": tag, "mapping": mapping },
+                    })
+                }
+                TypeEnumKind::Untagged | TypeEnumKind::External => json!({ "oneOf": schemas }),
+            }
+        }
+    };
+
+    let mut schema = with_description(
+        with_examples(with_constraints(schema, desc), desc, ctx),
+        desc.doc(),
+    );
+    if anchored {
+        if let Value::Object(obj) = &mut schema {
+            obj.insert("$anchor".into(), Value::String(anchor(desc.name())));
+        }
+    }
+    schema
+}
+
+/// Turn the relative targets of every `discriminator` mapping into JSON pointers from the root
+/// of the schema, as a schema does not know where it ends up while it is built
+fn resolve_mappings(schema: &mut Value, pointer: &str) {
+    match schema {
+        Value::Object(obj) => {
+            if let Some(Value::Object(mapping)) = obj
+                .get_mut("discriminator")
+                .and_then(|discriminator| discriminator.get_mut("mapping"))
+            {
+                for target in mapping.values_mut() {
+                    if let Value::String(target) = target {
+                        *target = format!("#{pointer}/{target}");
+                    }
+                }
+            }
+            for (key, value) in obj.iter_mut() {
+                let key = key.replace('~', "~0").replace('/', "~1");
+                resolve_mappings(value, &format!("{pointer}/{key}"));
+            }
+        }
+        Value::Array(values) => {
+            for (idx, value) in values.iter_mut().enumerate() {
+                resolve_mappings(value, &format!("{pointer}/{idx}"));
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to a [JSON Schema](https://json-schema.org/)
+///
+/// Documentation is carried over into the `description` keywords, struct fields that are not
+/// optional are listed as `required`. Optional values and optional struct fields also accept
+/// `null`, which is how serde writes a `None`. Tagged enums get a `discriminator` that maps every
+/// value of the tag to a JSON pointer to the schema of its variant.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::render::render_to_json_schema;
+///
+///     let ty_desc = Vec::<bool>::as_type_description();
+///     let schema = render_to_json_schema(&ty_desc);
+///
+///     assert_eq!(schema["type"], "array");
+///     assert_eq!(schema["items"]["type"], "boolean");
+/// ```
+pub fn render_to_json_schema(desc: &TypeDescription) -> Value {
+    render_to_json_schema_with_options(desc, &RenderOptions::default())
+}
+
+/// Render a [`struct@TypeDescription`] to a JSON Schema, using the given [`RenderOptions`]
+pub fn render_to_json_schema_with_options(
+    desc: &TypeDescription,
+    options: &RenderOptions,
+) -> Value {
+    let desc = options.prepare(desc);
+
+    let mut schema = desc_to_schema(&desc, &Context::new(&desc, Dialect::JsonSchema));
+    resolve_mappings(&mut schema, "");
+    if let Value::Object(obj) = &mut schema {
+        obj.insert(
+            "$schema".into(),
+            Value::String("https://json-schema.org/draft/2020-12/schema".into()),
+        );
+    }
+    schema
+}
+
+/// Render a [`struct@TypeDescription`] to an [OpenAPI 3.0](https://spec.openapis.org/oas/v3.0.3)
+/// schema object
+///
+/// The output follows the OpenAPI flavor of JSON Schema: optional values are marked as
+/// `nullable` and integers and floats carry a `format`. Tagged enums get no `discriminator`, as
+/// OpenAPI only uses one for `oneOf` members that refer to component schemas, while the variants
+/// are written inline. They are still told apart by the schema of their tag field. References to
+/// recursive types point to `#/components/schemas/<name>`, so the referenced type has to be
+/// registered there under its name, with characters that are not allowed replaced by `_`.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::render::to_openapi_schema;
+///
+///     let schema = to_openapi_schema(&Option::<u32>::as_type_description());
+///
+///     assert_eq!(schema["type"], "integer");
+///     assert_eq!(schema["format"], "int64");
+///     assert_eq!(schema["nullable"], true);
+/// ```
+pub fn to_openapi_schema(desc: &TypeDescription) -> Value {
+    to_openapi_schema_with_options(desc, &RenderOptions::default())
+}
+
+/// Render a [`struct@TypeDescription`] to an OpenAPI schema object, using the given
+/// [`RenderOptions`]
+pub fn to_openapi_schema_with_options(desc: &TypeDescription, options: &RenderOptions) -> Value {
+    let desc = options.prepare(desc);
+
+    desc_to_schema(&desc, &Context::new(&desc, Dialect::OpenApi))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        AsTypeDescription, Constraint, EnumVariant, EnumVariantRepresentation, StructField,
+        TypeDescription, TypeEnumKind, TypeKind,
+    };
+
+    use super::{render_to_json_schema, to_openapi_schema};
+
+    #[test]
+    fn render_nested_struct() {
+        let tls = TypeDescription::new(
+            "Tls".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "certificate",
+                Some("Path to the certificate"),
+                std::path::PathBuf::as_type_description(),
+                false,
+            )]),
+            Some("TLS settings"),
+        );
+        let desc = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "ports",
+                    Some("The ports to listen on"),
+                    Vec::<u16>::as_type_description(),
+                    false,
+                ),
+                StructField::new(
+                    "limits",
+                    None,
+                    std::collections::HashMap::<String, f64>::as_type_description(),
+                    false,
+                ),
+                StructField::new("tls", None, tls, true),
+            ]),
+            Some("A server"),
+        );
+
+        assert_eq!(
+            render_to_json_schema(&desc),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "description": "A server",
+                "type": "object",
+                "properties": {
+                    "ports": {
+                        "description": "The ports to listen on",
+                        "type": "array",
+                        "items": {
+                            "description": "An unsigned integer with 16 bits",
+                            "type": "integer",
+                            "minimum": 0,
+                        },
+                    },
+                    "limits": {
+                        "type": "object",
+                        "additionalProperties": {
+                            "description": "A floating point value with 64 bits",
+                            "type": "number",
+                        },
+                    },
+                    "tls": {
+                        "anyOf": [
+                            {
+                                "description": "TLS settings",
+                                "type": "object",
+                                "properties": {
+                                    "certificate": {
+                                        "description": "Path to the certificate",
+                                        "type": "string",
+                                    },
+                                },
+                                "required": ["certificate"],
+                            },
+                            { "type": "null" },
+                        ],
+                    },
+                },
+                "required": ["ports", "limits"],
+            })
+        );
+    }
+
+    #[test]
+    fn render_bytes() {
+        let schema = render_to_json_schema(&crate::Bytes::as_type_description());
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["contentEncoding"], "base64");
+    }
+
+    #[test]
+    fn render_one_of() {
+        let detailed = TypeDescription::new(
+            "Detailed".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "version",
+                None,
+                String::as_type_description(),
+                false,
+            )]),
+            None,
+        );
+        let desc = TypeDescription::one_of(
+            "Dependency".into(),
+            vec![String::as_type_description(), detailed],
+            None,
+        );
+
+        assert_eq!(
+            render_to_json_schema(&desc),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "oneOf": [
+                    { "description": "An UTF-8 string", "type": "string" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "version": { "description": "An UTF-8 string", "type": "string" },
+                        },
+                        "required": ["version"],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn render_recursive() {
+        let tree = TypeDescription::new(
+            "Tree".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "children",
+                None,
+                TypeDescription::new(
+                    "Children".into(),
+                    TypeKind::Array(Box::new(TypeDescription::new(
+                        "Tree".into(),
+                        TypeKind::Ref("Tree".into()),
+                        None,
+                    ))),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        );
+
+        assert_eq!(
+            render_to_json_schema(&tree),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "$anchor": "Tree",
+                "type": "object",
+                "properties": {
+                    "children": { "type": "array", "items": { "$ref": "#Tree" } },
+                },
+                "required": ["children"],
+            })
+        );
+    }
+
+    #[test]
+    fn render_recursive_twice() {
+        let tree = TypeDescription::new(
+            "Tree".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "children",
+                None,
+                TypeDescription::new(
+                    "Children".into(),
+                    TypeKind::Array(Box::new(TypeDescription::new(
+                        "Tree".into(),
+                        TypeKind::Ref("Tree".into()),
+                        None,
+                    ))),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        );
+        let forest = TypeDescription::new(
+            "Forest".into(),
+            TypeKind::Struct(vec![
+                StructField::new("a", None, tree.clone(), false),
+                StructField::new("b", None, tree, false),
+            ]),
+            None,
+        );
+
+        let schema = render_to_json_schema(&forest);
+        assert_eq!(schema["properties"]["a"]["$anchor"], "Tree");
+        assert_eq!(schema["properties"]["b"], json!({ "$ref": "#Tree" }));
+    }
+
+    #[test]
+    fn render_constraints() {
+        let port = u16::as_type_description()
+            .with_constraints(vec![Constraint::Min(1), Constraint::Max(65535)]);
+        let name = String::as_type_description().with_constraints(vec![
+            Constraint::MaxLen(16),
+            Constraint::Pattern("^[a-z]+$".into()),
+        ]);
+
+        let port_schema = render_to_json_schema(&port);
+        assert_eq!(port_schema["minimum"], 1);
+        assert_eq!(port_schema["maximum"], 65535);
+
+        let name_schema = render_to_json_schema(&name);
+        assert_eq!(name_schema["maxLength"], 16);
+        assert_eq!(name_schema["pattern"], "^[a-z]+$");
+    }
+
+    #[test]
+    fn render_examples() {
+        let port = u16::as_type_description().with_examples(vec!["8080".into(), "443".into()]);
+        let host = String::as_type_description().with_examples(vec!["8080".into()]);
+
+        assert_eq!(render_to_json_schema(&port)["examples"], json!([8080, 443]));
+        assert_eq!(render_to_json_schema(&host)["examples"], json!(["8080"]));
+        assert_eq!(to_openapi_schema(&port)["example"], json!(8080));
+    }
+
+    #[test]
+    fn render_enums() {
+        let untagged = TypeDescription::new(
+            "Level".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Untagged,
+                variants: vec![
+                    EnumVariant::new("Low", None, EnumVariantRepresentation::String("low".into())),
+                    EnumVariant::new(
+                        "High",
+                        None,
+                        EnumVariantRepresentation::String("high".into()),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        assert_eq!(
+            render_to_json_schema(&untagged),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "enum": ["low", "high"],
+            })
+        );
+
+        let tagged = TypeDescription::new(
+            "Storage".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Tagged("type".into()),
+                variants: vec![
+                    EnumVariant::new(
+                        "Memory",
+                        Some("Keep everything in memory"),
+                        EnumVariantRepresentation::String("Memory".into()),
+                    ),
+                    EnumVariant::new(
+                        "Disk",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(TypeDescription::new(
+                            "Disk".into(),
+                            TypeKind::Struct(vec![StructField::new(
+                                "path",
+                                None,
+                                String::as_type_description(),
+                                false,
+                            )]),
+                            None,
+                        ))),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        assert_eq!(
+            render_to_json_schema(&tagged),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "oneOf": [
+                    {
+                        "description": "Keep everything in memory",
+                        "type": "object",
+                        "properties": { "type": { "const": "Memory" } },
+                        "required": ["type"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "const": "Disk" },
+                            "path": { "description": "An UTF-8 string", "type": "string" },
+                        },
+                        "required": ["type", "path"],
+                    },
+                ],
+                "discriminator": {
+                    "propertyName": "type",
+                    "mapping": { "Memory": "#/oneOf/0", "Disk": "#/oneOf/1" },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn render_nested_discriminator() {
+        let storage = TypeDescription::new(
+            "Storage".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Tagged("type".into()),
+                variants: vec![EnumVariant::new(
+                    "Memory",
+                    None,
+                    EnumVariantRepresentation::String("memory".into()),
+                )],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        let desc = TypeDescription::new(
+            "Config".into(),
+            TypeKind::Struct(vec![StructField::new("data/cache", None, storage, false)]),
+            None,
+        );
+
+        let schema = render_to_json_schema(&desc);
+        assert_eq!(
+            schema["properties"]["data/cache"]["discriminator"]["mapping"],
+            json!({ "memory": "#/properties/data~1cache/oneOf/0" })
+        );
+    }
+
+    #[test]
+    fn render_nullable() {
+        // serde writes `vec![None]` as `[null]` and a `None` field as `"timeout": null`
+        let desc = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "limits",
+                    None,
+                    Vec::<Option<u8>>::as_type_description().without_docs(),
+                    false,
+                ),
+                StructField::new(
+                    "timeout",
+                    None,
+                    u64::as_type_description().without_docs(),
+                    true,
+                ),
+            ]),
+            None,
+        );
+
+        assert_eq!(
+            render_to_json_schema(&desc)["properties"],
+            json!({
+                "limits": {
+                    "type": "array",
+                    "items": {
+                        "anyOf": [{ "type": "integer", "minimum": 0 }, { "type": "null" }],
+                    },
+                },
+                "timeout": {
+                    "anyOf": [{ "type": "integer", "minimum": 0 }, { "type": "null" }],
+                },
+            })
+        );
+        assert_eq!(
+            desc.validate_value(&json!({ "limits": [null], "timeout": null })),
+            Ok(())
+        );
+
+        let renamed = desc.map_names(str::to_uppercase);
+        assert_eq!(
+            render_to_json_schema(&renamed)["properties"]["LIMITS"]["items"]["anyOf"][1],
+            json!({ "type": "null" })
+        );
+        assert_eq!(
+            renamed.validate_value(&json!({ "LIMITS": [null], "TIMEOUT": null })),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn openapi_struct() {
+        let desc = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "port",
+                    None,
+                    u16::as_type_description().without_docs(),
+                    false,
+                ),
+                StructField::new(
+                    "timeout",
+                    None,
+                    Option::<f64>::as_type_description().without_docs(),
+                    false,
+                ),
+                StructField::new(
+                    "address",
+                    None,
+                    <(String, i64)>::as_type_description().without_docs(),
+                    false,
+                ),
+                StructField::new("key", None, Vec::<u8>::as_type_description(), true),
+            ]),
+            None,
+        );
+
+        assert_eq!(
+            to_openapi_schema(&desc),
+            json!({
+                "type": "object",
+                "properties": {
+                    "port": { "type": "integer", "format": "int32", "minimum": 0 },
+                    "timeout": { "type": "number", "format": "double", "nullable": true },
+                    "address": {
+                        "type": "array",
+                        "items": {
+                            "oneOf": [
+                                { "type": "string" },
+                                { "type": "integer", "format": "int64" },
+                            ],
+                        },
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                    "key": {
+                        "type": "array",
+                        "items": {
+                            "description": "An unsigned integer with 8 bits",
+                            "type": "integer",
+                            "format": "int32",
+                            "minimum": 0,
+                        },
+                        "nullable": true,
+                    },
+                },
+                "required": ["port", "timeout", "address"],
+            })
+        );
+    }
+
+    #[test]
+    fn openapi_integer_formats() {
+        let format = |desc: TypeDescription| to_openapi_schema(&desc)["format"].clone();
+
+        assert_eq!(format(u16::as_type_description()), "int32");
+        assert_eq!(format(i32::as_type_description()), "int32");
+        assert_eq!(format(u32::as_type_description()), "int64");
+        assert_eq!(format(i64::as_type_description()), "int64");
+    }
+
+    #[test]
+    fn openapi_tagged_enum() {
+        let desc = TypeDescription::new(
+            "Shape".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Tagged("type".into()),
+                variants: vec![
+                    EnumVariant::new(
+                        "Circle",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(TypeDescription::new(
+                            "Circle".into(),
+                            TypeKind::Struct(vec![StructField::new(
+                                "radius",
+                                None,
+                                f32::as_type_description().without_docs(),
+                                false,
+                            )]),
+                            None,
+                        ))),
+                    ),
+                    EnumVariant::new(
+                        "Empty",
+                        None,
+                        EnumVariantRepresentation::String("Empty".into()),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            Some("A shape"),
+        );
+
+        assert_eq!(
+            to_openapi_schema(&desc),
+            json!({
+                "description": "A shape",
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "enum": ["Circle"] },
+                            "radius": { "type": "number", "format": "float" },
+                        },
+                        "required": ["type", "radius"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "type": { "enum": ["Empty"] } },
+                        "required": ["type"],
+                    },
+                ],
+            })
+        );
+    }
+}
@@ -4,32 +4,65 @@
 //   file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 
-use crate::{TypeDescription, TypeEnumKind};
+use super::{describe_constraints, explain_tagging, RenderOptions, TRUNCATED};
+use crate::TypeDescription;
 
-fn get_list_of_types(desc: &TypeDescription) -> IndexSet<&TypeDescription> {
-    let mut types = IndexSet::new();
+/// Collect the types to render a section for, together with whether the types nested in them
+/// are left out because of [`RenderOptions::max_depth`]
+fn get_list_of_types<'d>(
+    desc: &'d TypeDescription,
+    options: &RenderOptions,
+) -> IndexMap<&'d TypeDescription, bool> {
+    let mut types = IndexMap::new();
 
-    let mut remaining = vec![desc];
+    let mut remaining = vec![(desc, 0)];
 
-    while let Some(cur) = remaining.pop() {
-        types.insert(cur);
+    while let Some((cur, depth)) = remaining.pop() {
+        // A reference to a recursive type is described by the section of the type itself
+        if depth > 0 && matches!(cur.kind(), crate::TypeKind::Ref(_)) {
+            continue;
+        }
+
+        let truncated = options.too_deep(depth + 1) && !cur.children().is_empty();
+        // A type that is seen again higher up in the tree shows everything it contains
+        *types.entry(cur).or_insert(truncated) &= truncated;
+        if truncated {
+            continue;
+        }
 
         match cur.kind() {
             crate::TypeKind::Bool
             | crate::TypeKind::Integer { .. }
             | crate::TypeKind::Float { .. }
-            | crate::TypeKind::Enum(_, _)
-            | crate::TypeKind::String => (),
-            crate::TypeKind::Wrapped(wrapped) => remaining.push(wrapped),
-            crate::TypeKind::Array(arr) => remaining.push(arr),
+            | crate::TypeKind::String
+            | crate::TypeKind::Char
+            | crate::TypeKind::Bytes
+            | crate::TypeKind::Unit
+            | crate::TypeKind::Any
+            | crate::TypeKind::Ref(_) => (),
+            crate::TypeKind::Wrapped(wrapped) => remaining.push((wrapped, depth + 1)),
+            crate::TypeKind::Array(arr) => remaining.push((arr, depth + 1)),
+            crate::TypeKind::Tuple(elements) | crate::TypeKind::OneOf(elements) => {
+                remaining.extend(elements.iter().rev().map(|element| (element, depth + 1)))
+            }
             crate::TypeKind::HashMap { key, value } => {
-                remaining.push(value);
-                remaining.push(key);
+                remaining.push((value, depth + 1));
+                remaining.push((key, depth + 1));
             }
             crate::TypeKind::Struct(strt) => {
-                remaining.extend(strt.iter().rev().map(|field| &field.kind));
+                remaining.extend(strt.iter().rev().map(|field| (&field.kind, depth + 1)));
+            }
+            crate::TypeKind::Enum { variants, .. } => {
+                remaining.extend(variants.iter().rev().filter_map(
+                    |variant| match variant.repr() {
+                        crate::EnumVariantRepresentation::String(_) => None,
+                        crate::EnumVariantRepresentation::Wrapped(wrapped) => {
+                            Some((&**wrapped, depth + 1))
+                        }
+                    },
+                ));
             }
         }
     }
@@ -68,23 +101,109 @@ fn get_list_of_types(desc: &TypeDescription) -> IndexSet<&TypeDescription> {
 ///
 /// _Array Elements of String_
 pub fn render_to_markdown(desc: &TypeDescription) -> Result<String, std::fmt::Error> {
-    use std::fmt::Write;
-
-    let list_of_types = get_list_of_types(desc);
+    render_to_markdown_with_options(desc, &RenderOptions::default())
+}
 
+/// Render a [`struct@TypeDescription`] to a Markdown string, using the given [`RenderOptions`]
+pub fn render_to_markdown_with_options(
+    desc: &TypeDescription,
+    options: &RenderOptions,
+) -> Result<String, std::fmt::Error> {
     let mut markdown = String::new();
+    write_markdown_to(&mut markdown, desc, options)?;
+    Ok(markdown)
+}
+
+/// Write a [`struct@TypeDescription`] as Markdown directly into a writer
+///
+/// Writes the same Markdown as [`render_to_markdown`], without building the whole string in
+/// memory first.
+pub fn write_markdown<W: std::io::Write>(desc: &TypeDescription, writer: W) -> std::io::Result<()> {
+    write_markdown_with_options(desc, writer, &RenderOptions::default())
+}
 
-    for ty in list_of_types {
+/// Write a [`struct@TypeDescription`] as Markdown directly into a writer, using the given
+/// [`RenderOptions`]
+pub fn write_markdown_with_options<W: std::io::Write>(
+    desc: &TypeDescription,
+    writer: W,
+    options: &RenderOptions,
+) -> std::io::Result<()> {
+    let mut adapter = IoWriter {
+        inner: writer,
+        error: None,
+    };
+    write_markdown_to(&mut adapter, desc, options).map_err(|_| {
+        adapter
+            .error
+            .unwrap_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "formatter error"))
+    })
+}
+
+/// Passes formatted output on to an [`std::io::Write`], keeping the error that stopped it
+struct IoWriter<W> {
+    inner: W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write> std::fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            std::fmt::Error
+        })
+    }
+}
+
+fn write_markdown_to<M: std::fmt::Write>(
+    markdown: &mut M,
+    desc: &TypeDescription,
+    options: &RenderOptions,
+) -> std::fmt::Result {
+    let desc = options.prepare(desc);
+    let list_of_types = get_list_of_types(&desc, options);
+
+    for (ty, truncated) in list_of_types {
         writeln!(markdown, "# {}", ty.name())?;
         writeln!(markdown)?;
         if let Some(doc) = ty.doc() {
             writeln!(markdown, "{}", doc)?;
         }
+        if !ty.constraints().is_empty() {
+            writeln!(markdown)?;
+            writeln!(markdown, "_Constraints: {}_", describe_constraints(ty))?;
+        }
+        if !ty.examples().is_empty() {
+            writeln!(markdown)?;
+            writeln!(
+                markdown,
+                "_Examples: {}_",
+                ty.examples()
+                    .iter()
+                    .map(|example| format!("`{example}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
         match ty.kind() {
             crate::TypeKind::Bool
             | crate::TypeKind::Integer { .. }
             | crate::TypeKind::Float { .. }
-            | crate::TypeKind::String => (),
+            | crate::TypeKind::String
+            | crate::TypeKind::Char
+            | crate::TypeKind::Bytes => (),
+            crate::TypeKind::Unit => {
+                writeln!(markdown)?;
+                writeln!(markdown, "_No value_")?;
+            }
+            crate::TypeKind::Any => {
+                writeln!(markdown)?;
+                writeln!(markdown, "_Any value_")?;
+            }
+            crate::TypeKind::Ref(name) => {
+                writeln!(markdown)?;
+                writeln!(markdown, "_Refers back to {name}_")?;
+            }
             crate::TypeKind::Wrapped(wrapped_ty) => {
                 writeln!(markdown)?;
                 writeln!(markdown, "_Represented by {}_", wrapped_ty.name())?;
@@ -93,6 +212,30 @@ pub fn render_to_markdown(desc: &TypeDescription) -> Result<String, std::fmt::Er
                 writeln!(markdown)?;
                 writeln!(markdown, "_Array Elements of {}_", arr_ty.name())?;
             }
+            crate::TypeKind::Tuple(elements) => {
+                writeln!(markdown)?;
+                writeln!(
+                    markdown,
+                    "_Tuple Elements: {}_",
+                    elements
+                        .iter()
+                        .map(|element| element.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )?;
+            }
+            crate::TypeKind::OneOf(choices) => {
+                writeln!(markdown)?;
+                writeln!(
+                    markdown,
+                    "_One of: {}_",
+                    choices
+                        .iter()
+                        .map(|choice| choice.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )?;
+            }
             crate::TypeKind::HashMap {
                 key: key_ty,
                 value: value_ty,
@@ -113,37 +256,44 @@ pub fn render_to_markdown(desc: &TypeDescription) -> Result<String, std::fmt::Er
                 for field in strct {
                     writeln!(
                         markdown,
-                        "- `{}` ({}): {}",
-                        field.name(),
+                        "- `{}` ({}{}): {}",
+                        field.label(),
                         field.kind().name(),
+                        if field.optional() { ", optional" } else { "" },
                         field.doc().as_ref().unwrap_or(&"_No doc_")
                     )?;
                 }
             }
-            crate::TypeKind::Enum(tag_kind, variants) => {
+            crate::TypeKind::Enum {
+                tagging: tag_kind,
+                variants,
+                non_exhaustive,
+            } => {
+                writeln!(markdown)?;
                 write!(markdown, "**Variants:** ")?;
-                if let TypeEnumKind::Tagged(tag) = tag_kind {
-                    writeln!(markdown, "(Tagged with field `{tag}`)")?;
-                } else {
-                    writeln!(markdown, "Untagged")?;
-                };
+                write!(markdown, "{}.", explain_tagging(tag_kind))?;
+                if *non_exhaustive {
+                    write!(markdown, " _(more variants may be added)_")?;
+                }
+                writeln!(markdown)?;
                 writeln!(markdown)?;
 
                 for variant in variants {
                     match variant.repr() {
-                        crate::EnumVariantRepresentation::String(_) => {
+                        crate::EnumVariantRepresentation::String(repr) => {
                             writeln!(
                                 markdown,
-                                "- `{}`: {}",
-                                variant.name(),
+                                "- `{}` (String(`{}`)): {}",
+                                variant.label(),
+                                repr,
                                 variant.doc().unwrap_or("_No doc_")
                             )?;
                         }
                         crate::EnumVariantRepresentation::Wrapped(wrapped_ty) => {
                             writeln!(
                                 markdown,
-                                "- `{}` ({}): {}",
-                                variant.name(),
+                                "- `{}` (Wrapped({})): {}",
+                                variant.label(),
                                 wrapped_ty.name(),
                                 variant.doc().unwrap_or("_No doc_")
                             )?;
@@ -152,17 +302,24 @@ pub fn render_to_markdown(desc: &TypeDescription) -> Result<String, std::fmt::Er
                 }
             }
         }
+        if truncated {
+            writeln!(markdown)?;
+            writeln!(markdown, "_{TRUNCATED}_")?;
+        }
         writeln!(markdown)?;
     }
 
-    Ok(markdown)
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::AsTypeDescription;
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructBuilder, StructField,
+        TypeDescription, TypeEnumKind, TypeKind,
+    };
 
-    use super::render_to_markdown;
+    use super::{render_to_markdown, write_markdown};
 
     #[test]
     fn render_simple() {
@@ -171,6 +328,192 @@ mod tests {
 
         println!("{markdown}");
     }
+
+    #[test]
+    fn render_recursive_once() {
+        let tree = TypeDescription::new(
+            "Tree".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "children",
+                None,
+                TypeDescription::new(
+                    "Children".into(),
+                    TypeKind::Array(Box::new(TypeDescription::new(
+                        "Tree".into(),
+                        TypeKind::Ref("Tree".into()),
+                        None,
+                    ))),
+                    None,
+                ),
+                false,
+            )]),
+            None,
+        );
+
+        let markdown = render_to_markdown(&tree).unwrap();
+        assert_eq!(markdown.matches("# Tree\n").count(), 1);
+        assert!(markdown.contains("_Array Elements of Tree_"));
+    }
+
+    #[test]
+    fn write_matches_render() {
+        let ty_desc = StructBuilder::new("Server".into(), Some("A server"))
+            .field("port", None, u16::as_type_description())
+            .field("tags", None, Vec::<String>::as_type_description())
+            .build();
+
+        let mut written = Vec::new();
+        write_markdown(&ty_desc, &mut written).unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            render_to_markdown(&ty_desc).unwrap()
+        );
+    }
+
+    fn storage(kind: TypeEnumKind) -> TypeDescription {
+        TypeDescription::new(
+            "Storage".into(),
+            TypeKind::Enum {
+                tagging: kind,
+                variants: vec![
+                    EnumVariant::new(
+                        "Memory",
+                        Some("Keep everything in memory"),
+                        EnumVariantRepresentation::String("memory".into()),
+                    ),
+                    EnumVariant::new(
+                        "Disk",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(
+                            StructBuilder::new("Disk".into(), None)
+                                .field(
+                                    "path",
+                                    Some("Where to store"),
+                                    String::as_type_description(),
+                                )
+                                .build(),
+                        )),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            Some("Where to keep data"),
+        )
+    }
+
+    #[test]
+    fn render_tagged_enum() {
+        let markdown = render_to_markdown(&storage(TypeEnumKind::Tagged("type".into()))).unwrap();
+
+        assert_eq!(
+            markdown,
+            r#"# Storage
+
+Where to keep data
+
+**Variants:** The `type` field is required and holds the name of the variant.
+
+- `Memory` (String(`memory`)): Keep everything in memory
+- `Disk` (Wrapped(Disk)): _No doc_
+
+# Disk
+
+
+**Fields:**
+
+- `path` (String): Where to store
+
+# String
+
+An UTF-8 string
+
+"#
+        );
+    }
+
+    #[test]
+    fn render_untagged_enum() {
+        let markdown = render_to_markdown(&storage(TypeEnumKind::Untagged)).unwrap();
+
+        assert!(
+            markdown.contains("**Variants:** The variant is matched by the shape of the value.\n")
+        );
+        assert!(markdown.contains("- `Memory` (String(`memory`)): Keep everything in memory\n"));
+        assert!(markdown.contains("- `Disk` (Wrapped(Disk)): _No doc_\n"));
+    }
+
+    #[test]
+    fn render_map_key() {
+        let ty_desc = std::collections::HashMap::<std::path::PathBuf, u8>::as_type_description();
+        let markdown = render_to_markdown(&ty_desc).unwrap();
+
+        assert!(markdown.contains("_Key: String, Values: Integer_"));
+        assert!(markdown.contains("A filesystem path"));
+    }
+
+    #[test]
+    fn render_nested_struct() {
+        let tls = TypeDescription::new(
+            "Tls".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "certificate",
+                Some("Path to the certificate"),
+                std::path::PathBuf::as_type_description(),
+                false,
+            )]),
+            Some("TLS settings"),
+        );
+        let ty_desc = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "ports",
+                    Some("The ports to listen on"),
+                    Vec::<u16>::as_type_description(),
+                    false,
+                ),
+                StructField::new("tls", None, tls, true),
+            ]),
+            Some("A server"),
+        );
+
+        let markdown = render_to_markdown(&ty_desc).unwrap();
+
+        assert_eq!(
+            markdown,
+            r#"# Server
+
+A server
+
+**Fields:**
+
+- `ports` (Array of 'Integer's): The ports to listen on
+- `tls` (Tls, optional): _No doc_
+
+# Array of 'Integer's
+
+
+_Array Elements of Integer_
+
+# Integer
+
+An unsigned integer with 16 bits
+
+# Tls
+
+TLS settings
+
+**Fields:**
+
+- `certificate` (String): Path to the certificate
+
+# String
+
+A filesystem path
+
+"#
+        );
+    }
 }
 
 // #[derive(TypeDescription)]
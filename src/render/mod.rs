@@ -4,6 +4,56 @@
 //   file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_plain"
+))]
+use std::borrow::Cow;
+#[cfg(any(
+    feature = "render_graphql",
+    feature = "render_rust",
+    feature = "render_proto"
+))]
+use std::collections::BTreeSet;
+
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_plain",
+    feature = "render_html"
+))]
+use crate::Constraint;
+#[cfg(any(
+    feature = "render_signature",
+    feature = "render_graphql",
+    feature = "render_rust"
+))]
+use crate::EnumVariant;
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_plain",
+    feature = "render_signature",
+    feature = "render_graphql",
+    feature = "render_rust"
+))]
+use crate::EnumVariantRepresentation;
+#[cfg(any(feature = "render_markdown", feature = "render_plain"))]
+use crate::TypeEnumKind;
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_plain",
+    feature = "render_html",
+    feature = "render_signature",
+    feature = "render_graphql",
+    feature = "render_rust"
+))]
+use crate::{TypeDescription, TypeKind};
+
 #[cfg(feature = "render_markdown")]
 mod markdown;
 #[cfg(feature = "render_markdown")]
@@ -13,3 +63,485 @@ pub use markdown::*;
 mod terminal;
 #[cfg(feature = "render_terminal")]
 pub use terminal::*;
+
+#[cfg(feature = "render_json_schema")]
+mod json_schema;
+#[cfg(feature = "render_json_schema")]
+pub use json_schema::*;
+
+#[cfg(feature = "render_plain")]
+mod plain;
+#[cfg(feature = "render_plain")]
+pub use plain::*;
+
+#[cfg(feature = "render_html")]
+mod html;
+#[cfg(feature = "render_html")]
+pub use html::*;
+
+#[cfg(feature = "render_signature")]
+mod signature;
+#[cfg(feature = "render_signature")]
+pub use signature::*;
+
+#[cfg(feature = "render_graphql")]
+mod graphql;
+#[cfg(feature = "render_graphql")]
+pub use graphql::*;
+
+#[cfg(feature = "render_rust")]
+mod rust;
+#[cfg(feature = "render_rust")]
+pub use rust::*;
+
+#[cfg(feature = "render_proto")]
+mod proto;
+#[cfg(feature = "render_proto")]
+pub use proto::*;
+
+#[cfg(feature = "render_table")]
+mod table;
+#[cfg(feature = "render_table")]
+pub use table::*;
+
+/// Turn a name into a type name in `PascalCase`, e.g. `log-level` into `LogLevel`
+#[cfg(any(feature = "render_rust", feature = "render_proto"))]
+fn pascal_case(name: &str) -> String {
+    let mut pascal_case = String::new();
+    let mut upper = true;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            pascal_case.push(if upper { c.to_ascii_uppercase() } else { c });
+            upper = false;
+        } else {
+            upper = true;
+        }
+    }
+    if !pascal_case.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        pascal_case.insert(0, '_');
+    }
+    pascal_case
+}
+
+/// The definitions of named types, for renderers that define every type once and refer to it by
+/// name afterwards
+#[cfg(any(
+    feature = "render_graphql",
+    feature = "render_rust",
+    feature = "render_proto"
+))]
+#[derive(Default)]
+struct Definitions {
+    /// The definitions in the order their types were first encountered
+    definitions: Vec<String>,
+    /// The names of the types that are, or are being, defined
+    defined: BTreeSet<String>,
+}
+
+#[cfg(any(
+    feature = "render_graphql",
+    feature = "render_rust",
+    feature = "render_proto"
+))]
+impl Definitions {
+    /// Reserve the place of a definition, so that types are defined before the types they use
+    ///
+    /// Returns `None` if the type is already defined.
+    fn reserve(&mut self, name: &str) -> Option<usize> {
+        if !self.defined.insert(name.to_string()) {
+            return None;
+        }
+        self.definitions.push(String::new());
+        Some(self.definitions.len() - 1)
+    }
+
+    /// Fill in a definition reserved with [`Definitions::reserve`]
+    fn define(&mut self, index: usize, definition: String) {
+        self.definitions[index] = definition;
+    }
+}
+
+#[cfg(any(
+    feature = "render_graphql",
+    feature = "render_rust",
+    feature = "render_proto"
+))]
+impl IntoIterator for Definitions {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.definitions.into_iter()
+    }
+}
+
+/// Get the value an enum variant holds, or `None` if it is written as a plain string
+///
+/// The derive describes the content of a tuple variant with a layer named like the variant, which
+/// is looked through here.
+#[cfg(any(
+    feature = "render_signature",
+    feature = "render_graphql",
+    feature = "render_rust"
+))]
+fn variant_content(variant: &EnumVariant) -> Option<&TypeDescription> {
+    let EnumVariantRepresentation::Wrapped(inner) = variant.repr() else {
+        return None;
+    };
+    match inner.kind() {
+        TypeKind::Wrapped(content) if inner.name() == variant.name() => Some(content),
+        _ => Some(inner),
+    }
+}
+
+/// Explain how a value of an enum with the given tagging is written
+#[cfg(any(feature = "render_markdown", feature = "render_plain"))]
+fn explain_tagging(kind: &TypeEnumKind) -> String {
+    match kind {
+        TypeEnumKind::Tagged(tag) => {
+            format!("The `{tag}` field is required and holds the name of the variant")
+        }
+        TypeEnumKind::Adjacent { tag, content } => format!(
+            "The `{tag}` field is required and holds the name of the variant, the `{content}` \
+             field holds its data"
+        ),
+        TypeEnumKind::Untagged => "The variant is matched by the shape of the value".to_string(),
+        TypeEnumKind::External => {
+            "Variants with data are written as a table with the variant name as its only key"
+                .to_string()
+        }
+    }
+}
+
+/// A lookup from a documentation string to the text that replaces it, see
+/// [`RenderOptions::doc_resolver`]
+pub type DocResolver = dyn Fn(&str) -> Option<String>;
+
+/// List the constraints of a type in words
+///
+/// A minimum and maximum length that are the same are combined, e.g. into "exactly 2 characters".
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_plain",
+    feature = "render_html"
+))]
+fn describe_constraints(desc: &TypeDescription) -> String {
+    let constraints = desc.constraints();
+    let min_len = constraints.iter().find_map(|constraint| match constraint {
+        Constraint::MinLen(min) => Some(*min),
+        _ => None,
+    });
+    let max_len = constraints.iter().find_map(|constraint| match constraint {
+        Constraint::MaxLen(max) => Some(*max),
+        _ => None,
+    });
+
+    let mut described = vec![];
+    for constraint in constraints {
+        match (constraint, min_len.filter(|min| Some(*min) == max_len)) {
+            (Constraint::MinLen(_), Some(len)) => {
+                let mut kind = desc.kind();
+                while let TypeKind::Wrapped(inner) = kind {
+                    kind = inner.kind();
+                }
+                let unit = match kind {
+                    TypeKind::Array(_) | TypeKind::Tuple(_) => "elements",
+                    TypeKind::HashMap { .. } | TypeKind::Struct(_) => "entries",
+                    _ => "characters",
+                };
+                described.push(format!("exactly {len} {unit}"));
+            }
+            (Constraint::MaxLen(_), Some(_)) => (),
+            (constraint, _) => described.push(constraint.to_string()),
+        }
+    }
+    described.join(", ")
+}
+
+/// The marker that stands in for the parts of a description that are nested deeper than
+/// [`RenderOptions::max_depth`]
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_plain"
+))]
+const TRUNCATED: &str = "… (nested, truncated)";
+
+/// Options that change how the renderers output a [`struct@TypeDescription`]
+pub struct RenderOptions {
+    /// Only render types up to this many levels below the top-level type
+    ///
+    /// Whatever is nested deeper is replaced by `… (nested, truncated)`. This is only used by
+    /// the Markdown, terminal and plain text renderers, as the other formats have to describe the
+    /// whole type.
+    pub max_depth: Option<usize>,
+
+    /// Sort struct fields and enum variants alphabetically by name instead of keeping their
+    /// declaration order
+    pub sort_fields: bool,
+
+    /// Include the documentation of the types, fields and variants, which is the default
+    pub include_docs: bool,
+
+    /// The width to wrap the output of the terminal renderer at
+    pub terminal_width: usize,
+
+    /// Look up a replacement for every documentation string
+    ///
+    /// The documentation is used as a key, and replaced by the returned text if there is one.
+    /// This allows rendering the same description in different languages.
+    pub doc_resolver: Option<Box<DocResolver>>,
+}
+
+impl std::fmt::Debug for RenderOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RenderOptions")
+            .field("max_depth", &self.max_depth)
+            .field("sort_fields", &self.sort_fields)
+            .field("include_docs", &self.include_docs)
+            .field("terminal_width", &self.terminal_width)
+            .field("doc_resolver", &self.doc_resolver.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: None,
+            sort_fields: false,
+            include_docs: true,
+            terminal_width: 80,
+            doc_resolver: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// Apply the options that change the description itself before it is rendered
+    #[cfg(any(
+        feature = "render_markdown",
+        feature = "render_terminal",
+        feature = "render_json_schema",
+        feature = "render_plain"
+    ))]
+    pub(crate) fn prepare<'d>(&self, desc: &'d TypeDescription) -> Cow<'d, TypeDescription> {
+        if !self.sort_fields && self.include_docs && self.doc_resolver.is_none() {
+            return Cow::Borrowed(desc);
+        }
+
+        let mut desc = desc.clone();
+        if self.sort_fields {
+            sort_fields(&mut desc);
+        }
+        if !self.include_docs {
+            desc = desc.without_docs();
+        } else if let Some(resolver) = &self.doc_resolver {
+            resolve_docs(&mut desc, resolver);
+        }
+        Cow::Owned(desc)
+    }
+
+    /// Whether a type at the given depth below the top-level type is left out
+    #[cfg(any(
+        feature = "render_markdown",
+        feature = "render_terminal",
+        feature = "render_plain"
+    ))]
+    pub(crate) fn too_deep(&self, depth: usize) -> bool {
+        self.max_depth.map_or(false, |max_depth| depth > max_depth)
+    }
+}
+
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_plain"
+))]
+fn resolve_doc(doc: &mut Option<Cow<'static, str>>, resolver: &DocResolver) {
+    if let Some(resolved) = doc.as_deref().and_then(resolver) {
+        *doc = Some(Cow::Owned(resolved));
+    }
+}
+
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_plain"
+))]
+fn resolve_docs(desc: &mut TypeDescription, resolver: &DocResolver) {
+    resolve_doc(&mut desc.doc, resolver);
+
+    match &mut desc.kind {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Unit
+        | TypeKind::Any
+        | TypeKind::Ref(_) => (),
+        TypeKind::Wrapped(inner) | TypeKind::Array(inner) => resolve_docs(inner, resolver),
+        TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => elements
+            .iter_mut()
+            .for_each(|element| resolve_docs(element, resolver)),
+        TypeKind::HashMap { key, value } => {
+            resolve_docs(key, resolver);
+            resolve_docs(value, resolver);
+        }
+        TypeKind::Struct(fields) => {
+            for field in fields {
+                resolve_doc(&mut field.doc, resolver);
+                resolve_docs(&mut field.kind, resolver);
+            }
+        }
+        TypeKind::Enum { variants, .. } => {
+            for variant in variants {
+                resolve_doc(&mut variant.doc, resolver);
+                if let EnumVariantRepresentation::Wrapped(inner) = &mut variant.repr {
+                    resolve_docs(inner, resolver);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "render_markdown",
+    feature = "render_terminal",
+    feature = "render_json_schema",
+    feature = "render_plain"
+))]
+fn sort_fields(desc: &mut TypeDescription) {
+    match &mut desc.kind {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Unit
+        | TypeKind::Any
+        | TypeKind::Ref(_) => (),
+        TypeKind::Wrapped(inner) | TypeKind::Array(inner) => sort_fields(inner),
+        TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => {
+            elements.iter_mut().for_each(sort_fields)
+        }
+        TypeKind::HashMap { key, value } => {
+            sort_fields(key);
+            sort_fields(value);
+        }
+        TypeKind::Struct(fields) => {
+            fields.sort_by(|a, b| a.name().cmp(b.name()));
+            fields
+                .iter_mut()
+                .for_each(|field| sort_fields(&mut field.kind));
+        }
+        TypeKind::Enum { variants, .. } => {
+            variants.sort_by(|a, b| a.name().cmp(b.name()));
+            for variant in variants {
+                if let EnumVariantRepresentation::Wrapped(inner) = &mut variant.repr {
+                    sort_fields(inner);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnyValue, AsTypeDescription, StructBuilder, TypeDescription};
+
+    fn plugin() -> TypeDescription {
+        StructBuilder::new("Plugin".into(), Some("A plugin"))
+            .field("name", None, String::as_type_description())
+            .field(
+                "settings",
+                Some("Passed on as is"),
+                AnyValue::as_type_description(),
+            )
+            .field("extra", None, Vec::<AnyValue>::as_type_description())
+            .build()
+    }
+
+    #[cfg(any(
+        feature = "render_markdown",
+        feature = "render_terminal",
+        feature = "render_plain"
+    ))]
+    #[test]
+    fn render_truncated() {
+        let level = |name: &str, field: &'static str, inner: TypeDescription| {
+            StructBuilder::new(name.into(), None)
+                .field(field, None, inner)
+                .build()
+        };
+        let deepest = TypeDescription::new(
+            "Deepest".into(),
+            crate::TypeKind::Wrapped(Box::new(String::as_type_description())),
+            Some("Hidden at the bottom"),
+        );
+        let desc = level(
+            "Top",
+            "first",
+            level("Middle", "second", level("Bottom", "third", deepest)),
+        );
+        let options = super::RenderOptions {
+            max_depth: Some(2),
+            ..Default::default()
+        };
+
+        let rendered = [
+            #[cfg(feature = "render_markdown")]
+            super::render_to_markdown_with_options(&desc, &options).unwrap(),
+            #[cfg(feature = "render_terminal")]
+            super::render_to_terminal_string_with_options(&desc, &options).unwrap(),
+            #[cfg(feature = "render_plain")]
+            super::render_plain_with_options(&desc, &options),
+        ];
+        for rendered in rendered {
+            assert!(rendered.contains("Bottom"), "{rendered}");
+            assert!(rendered.contains(super::TRUNCATED), "{rendered}");
+            assert!(!rendered.contains("Hidden at the bottom"), "{rendered}");
+        }
+    }
+
+    #[test]
+    fn render_any() {
+        let desc = plugin();
+
+        #[cfg(feature = "render_markdown")]
+        assert!(super::render_to_markdown(&desc)
+            .unwrap()
+            .contains("_Any value_"));
+        #[cfg(feature = "render_terminal")]
+        assert!(super::render_to_terminal_string(&desc, 80)
+            .unwrap()
+            .contains("Any value"));
+        #[cfg(feature = "render_json_schema")]
+        assert_eq!(
+            super::render_to_json_schema(&desc)["properties"]["extra"]["items"],
+            serde_json::json!({ "description": "Any value" })
+        );
+        #[cfg(feature = "render_plain")]
+        assert!(super::render_plain(&desc).contains("settings: Any (any value) - Passed on as is"));
+        #[cfg(feature = "render_html")]
+        assert!(super::to_html(&desc).contains("<p class=\"any\">Any value</p>"));
+        #[cfg(feature = "render_signature")]
+        assert_eq!(
+            super::render_signature(&desc),
+            "Plugin { name: String, settings: Any, extra: [Any] }"
+        );
+        #[cfg(feature = "render_graphql")]
+        assert!(super::to_graphql_sdl(&desc).contains("settings: JSON!"));
+        #[cfg(feature = "render_rust")]
+        assert!(super::to_rust_source(&desc).contains("pub settings: serde_json::Value,"));
+        #[cfg(feature = "render_proto")]
+        assert!(super::to_proto(&desc, "plugins").contains("google.protobuf.Value settings = 2;"));
+        #[cfg(feature = "render_table")]
+        assert_eq!(super::to_table_rows(&desc)[2][0], "settings");
+    }
+}
@@ -0,0 +1,552 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use super::{describe_constraints, explain_tagging, RenderOptions, TRUNCATED};
+use crate::{EnumVariantRepresentation, TypeDescription, TypeEnumKind, TypeKind};
+
+fn render_line(
+    out: &mut String,
+    depth: usize,
+    label: Option<&str>,
+    desc: &TypeDescription,
+    doc: Option<&str>,
+) {
+    out.push_str(&"  ".repeat(depth));
+    if let Some(label) = label {
+        out.push_str(label);
+        out.push_str(": ");
+    }
+    out.push_str(desc.name());
+
+    if let TypeKind::Enum {
+        tagging: tag_kind, ..
+    } = desc.kind()
+    {
+        match tag_kind {
+            TypeEnumKind::Tagged(tag) => out.push_str(&format!(" (tagged with field `{tag}`)")),
+            TypeEnumKind::Adjacent { tag, content } => out.push_str(&format!(
+                " (tagged with field `{tag}`, content in field `{content}`)"
+            )),
+            TypeEnumKind::Untagged => out.push_str(" (untagged)"),
+            TypeEnumKind::External => out.push_str(" (externally tagged)"),
+        }
+    }
+
+    match desc.kind() {
+        TypeKind::Unit => out.push_str(" (no value)"),
+        TypeKind::Any => out.push_str(" (any value)"),
+        _ => (),
+    }
+
+    if let Some(doc) = doc {
+        out.push_str(" - ");
+        out.push_str(doc);
+    }
+
+    if !desc.constraints().is_empty() {
+        out.push_str(&format!(" [{}]", describe_constraints(desc)));
+    }
+
+    if !desc.examples().is_empty() {
+        out.push_str(&format!(" (examples: {})", desc.examples().join(", ")));
+    }
+
+    out.push('\n');
+}
+
+fn render_node(
+    out: &mut String,
+    options: &RenderOptions,
+    depth: usize,
+    label: Option<&str>,
+    desc: &TypeDescription,
+    doc: Option<&str>,
+) {
+    render_line(out, depth, label, desc, doc);
+    render_children(out, options, depth + 1, desc);
+}
+
+fn render_children(
+    out: &mut String,
+    options: &RenderOptions,
+    depth: usize,
+    desc: &TypeDescription,
+) {
+    if options.too_deep(depth) && !desc.children().is_empty() {
+        out.push_str(&"  ".repeat(depth));
+        out.push_str(TRUNCATED);
+        out.push('\n');
+        return;
+    }
+
+    match desc.kind() {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Unit
+        | TypeKind::Any
+        | TypeKind::Ref(_) => (),
+        TypeKind::Wrapped(inner) => render_node(out, options, depth, None, inner, inner.doc()),
+        TypeKind::Array(inner) => {
+            render_node(out, options, depth, Some("items"), inner, inner.doc())
+        }
+        TypeKind::Tuple(elements) => {
+            for (idx, element) in elements.iter().enumerate() {
+                render_node(
+                    out,
+                    options,
+                    depth,
+                    Some(&idx.to_string()),
+                    element,
+                    element.doc(),
+                );
+            }
+        }
+        TypeKind::OneOf(choices) => {
+            for choice in choices {
+                render_node(out, options, depth, Some("one of"), choice, choice.doc());
+            }
+        }
+        TypeKind::HashMap { key, value } => {
+            render_node(out, options, depth, Some("key"), key, key.doc());
+            render_node(out, options, depth, Some("value"), value, value.doc());
+        }
+        TypeKind::Struct(fields) => {
+            for field in fields {
+                let label = if field.optional() {
+                    format!("{} (optional)", field.label())
+                } else {
+                    field.label().to_string()
+                };
+                render_node(
+                    out,
+                    options,
+                    depth,
+                    Some(&label),
+                    field.kind(),
+                    field.doc().or(field.kind().doc()),
+                );
+            }
+        }
+        TypeKind::Enum {
+            tagging: tag_kind,
+            variants,
+            non_exhaustive,
+        } => {
+            out.push_str(&"  ".repeat(depth));
+            out.push_str(&explain_tagging(tag_kind));
+            if *non_exhaustive {
+                out.push_str(" (more variants may be added)");
+            }
+            out.push('\n');
+
+            for variant in variants {
+                out.push_str(&"  ".repeat(depth));
+                match variant.repr() {
+                    EnumVariantRepresentation::String(repr) => {
+                        out.push_str(&format!("{}: String(\"{}\")", variant.label(), repr));
+                    }
+                    EnumVariantRepresentation::Wrapped(inner) => {
+                        out.push_str(&format!("{}: Wrapped({})", variant.label(), inner.name()));
+                    }
+                }
+                if let Some(doc) = variant.doc() {
+                    out.push_str(" - ");
+                    out.push_str(doc);
+                }
+                out.push('\n');
+
+                if let EnumVariantRepresentation::Wrapped(inner) = variant.repr() {
+                    render_children(out, options, depth + 1, inner);
+                }
+            }
+        }
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to a plain text tree
+///
+/// Every type is printed on its own line, indented below the type that contains it. Struct fields
+/// and enum variants are listed in declaration order, and no terminal control codes are emitted,
+/// which makes the output suitable for logs and for comparing in tests.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::render::render_plain;
+///
+///     let ty_desc = std::collections::HashMap::<String, Vec<bool>>::as_type_description();
+///
+///     assert_eq!(
+///         render_plain(&ty_desc),
+///         "Table of 'String => Array of 'Boolean's'\n  key: String - An UTF-8 string\n  value: Array of 'Boolean's\n    items: Boolean - A boolean\n"
+///     );
+/// ```
+pub fn render_plain(desc: &TypeDescription) -> String {
+    render_plain_with_options(desc, &RenderOptions::default())
+}
+
+/// Render a [`struct@TypeDescription`] to a plain text tree, using the given [`RenderOptions`]
+pub fn render_plain_with_options(desc: &TypeDescription, options: &RenderOptions) -> String {
+    let desc = options.prepare(desc);
+    let mut out = String::new();
+    render_node(&mut out, options, 0, None, &desc, desc.doc());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, Constraint, EnumVariant, EnumVariantRepresentation, StructBuilder,
+        StructField, TypeDescription, TypeEnumKind, TypeKind,
+    };
+
+    use super::{render_plain, render_plain_with_options};
+    use crate::render::RenderOptions;
+
+    #[test]
+    fn render_nested_struct() {
+        let tls = TypeDescription::new(
+            "Tls".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "certificate",
+                Some("Path to the certificate"),
+                std::path::PathBuf::as_type_description(),
+                false,
+            )]),
+            Some("TLS settings"),
+        );
+        let level = TypeDescription::new(
+            "Level".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Untagged,
+                variants: vec![
+                    EnumVariant::new(
+                        "Low",
+                        Some("Only errors"),
+                        EnumVariantRepresentation::String("low".into()),
+                    ),
+                    EnumVariant::new(
+                        "High",
+                        None,
+                        EnumVariantRepresentation::String("high".into()),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        let ty_desc = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "ports",
+                    Some("The ports to listen on"),
+                    Vec::<u16>::as_type_description().with_constraints(vec![Constraint::MinLen(1)]),
+                    false,
+                ),
+                StructField::new("tls", None, tls, true),
+                StructField::new("log_level", None, level, false),
+            ]),
+            Some("A server"),
+        );
+
+        assert_eq!(
+            render_plain(&ty_desc),
+            r#"Server - A server
+  ports: Array of 'Integer's - The ports to listen on [minimum length 1]
+    items: Integer - An unsigned integer with 16 bits
+  tls (optional): Tls - TLS settings
+    certificate: String - Path to the certificate
+  log_level: Level (untagged)
+    The variant is matched by the shape of the value
+    Low: String("low") - Only errors
+    High: String("high")
+"#
+        );
+    }
+
+    #[test]
+    fn render_fixed_length() {
+        let ty_desc = TypeDescription::new(
+            "Address".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "country",
+                    None,
+                    String::as_type_description()
+                        .with_constraints(vec![Constraint::MinLen(2), Constraint::MaxLen(2)]),
+                    false,
+                ),
+                StructField::new(
+                    "lines",
+                    None,
+                    Vec::<String>::as_type_description()
+                        .with_constraints(vec![Constraint::MaxLen(3), Constraint::MinLen(3)]),
+                    false,
+                ),
+                StructField::new(
+                    "zip",
+                    None,
+                    String::as_type_description()
+                        .with_constraints(vec![Constraint::MinLen(4), Constraint::MaxLen(10)]),
+                    false,
+                ),
+            ]),
+            None,
+        );
+
+        assert_eq!(
+            render_plain(&ty_desc),
+            r#"Address
+  country: String - An UTF-8 string [exactly 2 characters]
+  lines: Array of 'String's [exactly 3 elements]
+    items: String - An UTF-8 string
+  zip: String - An UTF-8 string [minimum length 4, maximum length 10]
+"#
+        );
+    }
+
+    fn storage(kind: TypeEnumKind) -> TypeDescription {
+        TypeDescription::new(
+            "Storage".into(),
+            TypeKind::Enum {
+                tagging: kind,
+                variants: vec![
+                    EnumVariant::new(
+                        "Memory",
+                        Some("Keep everything in memory"),
+                        EnumVariantRepresentation::String("memory".into()),
+                    ),
+                    EnumVariant::new(
+                        "Disk",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(
+                            StructBuilder::new("Disk".into(), None)
+                                .field(
+                                    "path",
+                                    Some("Where to store"),
+                                    String::as_type_description(),
+                                )
+                                .build(),
+                        )),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            Some("Where to keep data"),
+        )
+    }
+
+    #[test]
+    fn render_enums() {
+        assert_eq!(
+            render_plain(&storage(TypeEnumKind::Tagged("type".into()))),
+            r#"Storage (tagged with field `type`) - Where to keep data
+  The `type` field is required and holds the name of the variant
+  Memory: String("memory") - Keep everything in memory
+  Disk: Wrapped(Disk)
+    path: String - Where to store
+"#
+        );
+        assert_eq!(
+            render_plain(&storage(TypeEnumKind::Adjacent {
+                tag: "type".into(),
+                content: "data".into(),
+            })),
+            r#"Storage (tagged with field `type`, content in field `data`) - Where to keep data
+  The `type` field is required and holds the name of the variant, the `data` field holds its data
+  Memory: String("memory") - Keep everything in memory
+  Disk: Wrapped(Disk)
+    path: String - Where to store
+"#
+        );
+        assert_eq!(
+            render_plain(&storage(TypeEnumKind::Untagged)),
+            r#"Storage (untagged) - Where to keep data
+  The variant is matched by the shape of the value
+  Memory: String("memory") - Keep everything in memory
+  Disk: Wrapped(Disk)
+    path: String - Where to store
+"#
+        );
+    }
+
+    #[test]
+    fn render_non_exhaustive() {
+        let mut desc = storage(TypeEnumKind::External);
+        if let TypeKind::Enum { non_exhaustive, .. } = desc.kind_mut() {
+            *non_exhaustive = true;
+        }
+
+        assert_eq!(
+            render_plain(&desc),
+            r#"Storage (externally tagged) - Where to keep data
+  Variants with data are written as a table with the variant name as its only key (more variants may be added)
+  Memory: String("memory") - Keep everything in memory
+  Disk: Wrapped(Disk)
+    path: String - Where to store
+"#
+        );
+    }
+
+    #[test]
+    fn render_unit() {
+        assert_eq!(
+            render_plain(&<()>::as_type_description()),
+            "Unit (no value) - The unit value `()`\n"
+        );
+    }
+
+    #[test]
+    fn render_sorted() {
+        let config = |fields: Vec<StructField>| {
+            TypeDescription::new("Config".into(), TypeKind::Struct(fields), None)
+        };
+        let name = StructField::new("name", None, String::as_type_description(), false);
+        let port = StructField::new("port", None, u16::as_type_description(), false);
+        let verbose = StructField::new("verbose", None, bool::as_type_description(), true);
+
+        let declared = config(vec![port.clone(), verbose.clone(), name.clone()]);
+        let shuffled = config(vec![verbose, name, port]);
+        let options = RenderOptions {
+            sort_fields: true,
+            ..Default::default()
+        };
+
+        let rendered = render_plain_with_options(&declared, &options);
+        assert_eq!(rendered, render_plain_with_options(&shuffled, &options));
+        assert_eq!(
+            rendered,
+            r#"Config
+  name: String - An UTF-8 string
+  port: Integer - An unsigned integer with 16 bits
+  verbose (optional): Boolean - A boolean
+"#
+        );
+        assert_ne!(render_plain(&declared), render_plain(&shuffled));
+    }
+
+    #[test]
+    fn render_max_depth() {
+        let desc = StructBuilder::new("Config".into(), Some("The configuration"))
+            .field("port", None, u16::as_type_description())
+            .field("peers", None, Vec::<(String, u16)>::as_type_description())
+            .build();
+        let options = |max_depth| RenderOptions {
+            max_depth: Some(max_depth),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            render_plain_with_options(&desc, &options(0)),
+            "Config - The configuration\n  … (nested, truncated)\n"
+        );
+        assert_eq!(
+            render_plain_with_options(&desc, &options(1)),
+            r#"Config - The configuration
+  port: Integer - An unsigned integer with 16 bits
+  peers: Array of 'Tuple of ('String', 'Integer')'s
+    … (nested, truncated)
+"#
+        );
+        assert_eq!(
+            render_plain_with_options(&desc, &options(3)),
+            render_plain(&desc)
+        );
+    }
+
+    #[test]
+    fn render_without_docs() {
+        let desc = StructBuilder::new("Config".into(), Some("The configuration"))
+            .field("port", Some("Where to listen"), u16::as_type_description())
+            .build();
+        let options = RenderOptions {
+            include_docs: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            render_plain_with_options(&desc, &options),
+            "Config\n  port: Integer\n"
+        );
+    }
+
+    #[test]
+    fn render_examples() {
+        let desc = StructBuilder::new("Config".into(), None)
+            .field(
+                "port",
+                None,
+                u16::as_type_description()
+                    .without_docs()
+                    .with_examples(vec!["8080".into(), "443".into()]),
+            )
+            .build();
+
+        assert_eq!(
+            render_plain(&desc),
+            "Config\n  port: Integer (examples: 8080, 443)\n"
+        );
+    }
+
+    #[test]
+    fn render_labels() {
+        let desc = TypeDescription::new(
+            "Config".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "bind-address",
+                None,
+                String::as_type_description().without_docs(),
+                false,
+            )
+            .with_label("Bind address")]),
+            None,
+        );
+
+        assert_eq!(render_plain(&desc), "Config\n  Bind address: String\n");
+    }
+
+    #[test]
+    fn render_resolved_docs() {
+        let desc = TypeDescription::new(
+            "Config".into(),
+            TypeKind::Struct(vec![
+                StructField::new(
+                    "port",
+                    Some("config.port"),
+                    u16::as_type_description(),
+                    false,
+                ),
+                StructField::new(
+                    "name",
+                    Some("config.name"),
+                    String::as_type_description(),
+                    false,
+                ),
+            ]),
+            None,
+        );
+        let options = RenderOptions {
+            doc_resolver: Some(Box::new(|key| {
+                (key == "config.port").then(|| "Der Port, auf dem gelauscht wird".to_string())
+            })),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            render_plain_with_options(&desc, &options),
+            r#"Config
+  port: Integer - Der Port, auf dem gelauscht wird
+  name: String - config.name
+"#
+        );
+    }
+}
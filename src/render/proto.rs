@@ -0,0 +1,348 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use super::{pascal_case, Definitions};
+use crate::{EnumVariantRepresentation, Sign, StructField, TypeDescription, TypeKind};
+
+/// A message for values that have no proto equivalent
+const VALUE: (&str, &str) = ("google.protobuf.Value", "google/protobuf/struct.proto");
+/// A message for the absence of a value
+const EMPTY: (&str, &str) = ("google.protobuf.Empty", "google/protobuf/empty.proto");
+
+/// Turn a name into a field name, e.g. `log-level` into `log_level`
+fn field_name(name: &str) -> String {
+    let mut field_name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if !field_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        field_name.insert(0, 'f');
+    }
+    field_name
+}
+
+/// Turn a name into an enum value name, e.g. `LogLevel` into `LOG_LEVEL`
+fn screaming_snake_case(name: &str) -> String {
+    let mut screaming = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            screaming.push('_');
+            previous_lower = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && previous_lower {
+            screaming.push('_');
+        }
+        screaming.push(c.to_ascii_uppercase());
+        previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+    }
+    screaming
+}
+
+fn write_comment(out: &mut String, indent: &str, doc: Option<&str>) {
+    for line in doc.into_iter().flat_map(str::lines) {
+        if line.is_empty() {
+            writeln!(out, "{indent}//").unwrap();
+        } else {
+            writeln!(out, "{indent}// {line}").unwrap();
+        }
+    }
+}
+
+/// How a field of a message is declared
+enum Field {
+    Single(String),
+    Optional(String),
+    Repeated(String),
+    Map(String, String),
+}
+
+impl Field {
+    fn declare(&self, name: &str, number: usize) -> String {
+        match self {
+            Field::Single(ty) => format!("{ty} {name} = {number};"),
+            Field::Optional(ty) => format!("optional {ty} {name} = {number};"),
+            Field::Repeated(ty) => format!("repeated {ty} {name} = {number};"),
+            Field::Map(key, value) => format!("map<{key}, {value}> {name} = {number};"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Proto {
+    definitions: Definitions,
+    /// The files that have to be imported for well-known types
+    imports: BTreeSet<&'static str>,
+}
+
+impl Proto {
+    fn well_known(&mut self, (name, import): (&'static str, &'static str)) -> String {
+        self.imports.insert(import);
+        name.to_string()
+    }
+
+    /// Get the type of a single value, defining it and the types it uses on the way
+    fn scalar(&mut self, desc: &TypeDescription) -> String {
+        let name = pascal_case(desc.name());
+        match desc.kind() {
+            TypeKind::Bool => "bool".to_string(),
+            TypeKind::Integer { sign, size } => match (sign, *size <= 32) {
+                (Sign::Signed, true) => "int32".to_string(),
+                (Sign::Unsigned, true) => "uint32".to_string(),
+                (Sign::Signed, false) => "int64".to_string(),
+                (Sign::Unsigned, false) => "uint64".to_string(),
+            },
+            TypeKind::Float { size } if *size <= 32 => "float".to_string(),
+            TypeKind::Float { .. } => "double".to_string(),
+            TypeKind::String | TypeKind::Char => "string".to_string(),
+            TypeKind::Bytes => "bytes".to_string(),
+            TypeKind::Unit => self.well_known(EMPTY),
+            TypeKind::Ref(name) => pascal_case(name),
+            TypeKind::Wrapped(inner) => self.scalar(inner),
+            TypeKind::Struct(fields) => {
+                self.define_message(&name, desc.doc(), fields);
+                name
+            }
+            TypeKind::Enum { variants, .. }
+                if variants.iter().all(|variant| {
+                    matches!(variant.repr(), EnumVariantRepresentation::String(_))
+                }) =>
+            {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let prefix = screaming_snake_case(&name);
+                    let mut definition = String::new();
+                    write_comment(&mut definition, "", desc.doc());
+                    writeln!(definition, "enum {name} {{").unwrap();
+                    for (number, variant) in variants.iter().enumerate() {
+                        write_comment(&mut definition, "  ", variant.doc());
+                        writeln!(
+                            definition,
+                            "  {prefix}_{} = {number};",
+                            screaming_snake_case(variant.name())
+                        )
+                        .unwrap();
+                    }
+                    definition.push('}');
+                    self.definitions.define(index, definition);
+                }
+                name
+            }
+            TypeKind::Enum { variants, .. } => {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let mut definition = String::new();
+                    write_comment(&mut definition, "", desc.doc());
+                    writeln!(definition, "message {name} {{").unwrap();
+                    writeln!(definition, "  oneof variant {{").unwrap();
+                    for (number, variant) in variants.iter().enumerate() {
+                        let ty = match variant.repr() {
+                            EnumVariantRepresentation::String(_) => self.well_known(EMPTY),
+                            EnumVariantRepresentation::Wrapped(inner) => match self.field(inner) {
+                                Field::Single(ty) | Field::Optional(ty) => ty,
+                                // Fields of a oneof cannot be repeated
+                                Field::Repeated(_) | Field::Map(..) => self.well_known(VALUE),
+                            },
+                        };
+                        write_comment(&mut definition, "    ", variant.doc());
+                        writeln!(
+                            definition,
+                            "    {}",
+                            Field::Single(ty).declare(
+                                &screaming_snake_case(variant.name()).to_ascii_lowercase(),
+                                number + 1
+                            )
+                        )
+                        .unwrap();
+                    }
+                    writeln!(definition, "  }}").unwrap();
+                    definition.push('}');
+                    self.definitions.define(index, definition);
+                }
+                name
+            }
+            TypeKind::Any
+            | TypeKind::Array(_)
+            | TypeKind::Tuple(_)
+            | TypeKind::HashMap { .. }
+            | TypeKind::OneOf(_) => self.well_known(VALUE),
+        }
+    }
+
+    /// Get how a value is declared as a field
+    fn field(&mut self, desc: &TypeDescription) -> Field {
+        match desc.kind() {
+            TypeKind::Wrapped(inner) if desc.is_optional() => {
+                match self.field(inner) {
+                    Field::Single(ty) => Field::Optional(ty),
+                    // Repeated fields and maps are empty instead of absent
+                    field => field,
+                }
+            }
+            TypeKind::Wrapped(inner) => self.field(inner),
+            TypeKind::Array(inner) => match self.field(inner) {
+                Field::Single(ty) => Field::Repeated(ty),
+                // Nested collections have no proto equivalent
+                _ => Field::Repeated(self.well_known(VALUE)),
+            },
+            TypeKind::HashMap { key, value } => {
+                // Only integers and strings can be keys, and values cannot be collections
+                let key = match self.scalar(key).as_str() {
+                    ty @ ("int32" | "uint32" | "int64" | "uint64" | "bool" | "string") => {
+                        ty.to_string()
+                    }
+                    _ => return Field::Single(self.well_known(VALUE)),
+                };
+                match self.field(value) {
+                    Field::Single(value) | Field::Optional(value) => Field::Map(key, value),
+                    Field::Repeated(_) | Field::Map(..) => Field::Map(key, self.well_known(VALUE)),
+                }
+            }
+            _ => Field::Single(self.scalar(desc)),
+        }
+    }
+
+    fn define_message(&mut self, name: &str, doc: Option<&str>, fields: &[StructField]) {
+        let Some(index) = self.definitions.reserve(name) else {
+            return;
+        };
+
+        let mut definition = String::new();
+        write_comment(&mut definition, "", doc);
+        writeln!(definition, "message {name} {{").unwrap();
+        for (number, field) in fields.iter().enumerate() {
+            let declaration = match self.field(field.kind()) {
+                Field::Single(ty) if field.optional() => Field::Optional(ty),
+                declaration => declaration,
+            };
+            write_comment(&mut definition, "  ", field.doc());
+            writeln!(
+                definition,
+                "  {}",
+                declaration.declare(&field_name(field.name()), number + 1)
+            )
+            .unwrap();
+        }
+        definition.push('}');
+        self.definitions.define(index, definition);
+    }
+}
+
+/// Render a [`struct@TypeDescription`] to [proto3](https://protobuf.dev/programming-guides/proto3/)
+/// message definitions in the given package
+///
+/// Structs become messages, with their fields numbered in declaration order. Arrays become
+/// `repeated` fields and maps with integer or string keys `map<..>` fields. Enums whose variants
+/// are all plain strings become proto enums, other enums messages with a `oneof`. Values that
+/// have no proto equivalent, like tuples or nested arrays, fall back to `google.protobuf.Value`.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, StructBuilder};
+///     use type_description::render::to_proto;
+///
+///     let server = StructBuilder::new("Server".into(), None)
+///         .field("port", None, u16::as_type_description())
+///         .build();
+///
+///     assert!(to_proto(&server, "config").contains("message Server {\n  uint32 port = 1;\n}"));
+/// ```
+pub fn to_proto(desc: &TypeDescription, package: &str) -> String {
+    let mut proto = Proto::default();
+    proto.scalar(desc);
+
+    let mut out = format!("syntax = \"proto3\";\n\npackage {package};\n");
+    if !proto.imports.is_empty() {
+        out.push('\n');
+        for import in &proto.imports {
+            writeln!(out, "import \"{import}\";").unwrap();
+        }
+    }
+    for definition in proto.definitions {
+        write!(out, "\n{definition}\n").unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructBuilder, TypeDescription,
+        TypeEnumKind, TypeKind,
+    };
+
+    use super::to_proto;
+
+    #[test]
+    fn proto_message() {
+        let level = TypeDescription::new(
+            "LogLevel".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Untagged,
+                variants: vec![
+                    EnumVariant::new(
+                        "Low",
+                        Some("Only errors"),
+                        EnumVariantRepresentation::String("low".into()),
+                    ),
+                    EnumVariant::new(
+                        "High",
+                        None,
+                        EnumVariantRepresentation::String("high".into()),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        let server = StructBuilder::new("Server".into(), Some("A server"))
+            .field(
+                "port",
+                Some("The port to listen on"),
+                u16::as_type_description(),
+            )
+            .field("tags", None, Vec::<String>::as_type_description())
+            .field(
+                "limits",
+                None,
+                std::collections::HashMap::<String, f64>::as_type_description(),
+            )
+            .optional_field("timeout", None, i64::as_type_description())
+            .field("level", None, level)
+            .field("extra", None, <(u8, bool)>::as_type_description())
+            .build();
+
+        assert_eq!(
+            to_proto(&server, "config.v1"),
+            r#"syntax = "proto3";
+
+package config.v1;
+
+import "google/protobuf/struct.proto";
+
+// A server
+message Server {
+  // The port to listen on
+  uint32 port = 1;
+  repeated string tags = 2;
+  map<string, double> limits = 3;
+  optional int64 timeout = 4;
+  LogLevel level = 5;
+  google.protobuf.Value extra = 6;
+}
+
+enum LogLevel {
+  // Only errors
+  LOG_LEVEL_LOW = 0;
+  LOG_LEVEL_HIGH = 1;
+}
+"#
+        );
+    }
+}
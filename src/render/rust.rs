@@ -0,0 +1,284 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use std::fmt::Write;
+
+use super::{pascal_case, variant_content, Definitions};
+use crate::{Sign, StructField, TypeDescription, TypeKind};
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "try", "type", "unsafe", "use", "where",
+    "while", "yield",
+];
+
+/// Turn a name into a field name, escaping keywords, e.g. `type` into `r#type`
+fn field_name(name: &str) -> String {
+    let mut field_name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if !field_name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        field_name.insert(0, '_');
+    }
+    if KEYWORDS.contains(&field_name.as_str()) {
+        field_name.insert_str(0, "r#");
+    }
+    field_name
+}
+
+fn write_doc(out: &mut String, indent: &str, doc: Option<&str>) {
+    for line in doc.into_iter().flat_map(str::lines) {
+        if line.is_empty() {
+            writeln!(out, "{indent}///").unwrap();
+        } else {
+            writeln!(out, "{indent}/// {line}").unwrap();
+        }
+    }
+}
+
+#[derive(Default)]
+struct Source {
+    definitions: Definitions,
+}
+
+impl Source {
+    fn write_fields(&mut self, out: &mut String, indent: &str, fields: &[StructField]) {
+        for field in fields {
+            let mut ty = self.reference(field.kind());
+            if field.optional() && !ty.starts_with("Option<") {
+                ty = format!("Option<{ty}>");
+            }
+            write_doc(out, indent, field.doc());
+            writeln!(out, "{indent}pub {}: {ty},", field_name(field.name())).unwrap();
+        }
+    }
+
+    /// Get the Rust type of a description, defining it and the types it uses on the way
+    fn reference(&mut self, desc: &TypeDescription) -> String {
+        let name = pascal_case(desc.name());
+        match desc.kind() {
+            TypeKind::Bool => "bool".to_string(),
+            TypeKind::Integer { sign, size } => match sign {
+                Sign::Signed => format!("i{size}"),
+                Sign::Unsigned => format!("u{size}"),
+            },
+            TypeKind::Float { size } => format!("f{size}"),
+            TypeKind::String => "String".to_string(),
+            TypeKind::Char => "char".to_string(),
+            TypeKind::Bytes => "Vec<u8>".to_string(),
+            TypeKind::Unit => "()".to_string(),
+            TypeKind::Any => "serde_json::Value".to_string(),
+            TypeKind::Ref(name) => pascal_case(name),
+            TypeKind::Wrapped(inner) if desc.is_optional() => {
+                format!("Option<{}>", self.reference(inner))
+            }
+            TypeKind::Wrapped(inner) => {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let mut definition = String::new();
+                    write_doc(&mut definition, "", desc.doc());
+                    write!(
+                        definition,
+                        "pub struct {name}(pub {});",
+                        self.reference(inner)
+                    )
+                    .unwrap();
+                    self.definitions.define(index, definition);
+                }
+                name
+            }
+            TypeKind::Array(inner) => format!("Vec<{}>", self.reference(inner)),
+            TypeKind::Tuple(elements) => format!(
+                "({})",
+                elements
+                    .iter()
+                    .map(|element| self.reference(element))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TypeKind::HashMap { key, value } => format!(
+                "std::collections::HashMap<{}, {}>",
+                self.reference(key),
+                self.reference(value)
+            ),
+            TypeKind::Struct(fields) => {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let mut definition = String::new();
+                    write_doc(&mut definition, "", desc.doc());
+                    writeln!(definition, "pub struct {name} {{").unwrap();
+                    self.write_fields(&mut definition, "    ", fields);
+                    definition.push('}');
+                    self.definitions.define(index, definition);
+                }
+                name
+            }
+            TypeKind::OneOf(choices) => {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let mut definition = String::new();
+                    write_doc(&mut definition, "", desc.doc());
+                    writeln!(definition, "pub enum {name} {{").unwrap();
+                    for choice in choices {
+                        let ty = self.reference(choice);
+                        writeln!(definition, "    {}({ty}),", pascal_case(choice.name())).unwrap();
+                    }
+                    definition.push('}');
+                    self.definitions.define(index, definition);
+                }
+                name
+            }
+            TypeKind::Enum { variants, .. } => {
+                if let Some(index) = self.definitions.reserve(&name) {
+                    let mut definition = String::new();
+                    write_doc(&mut definition, "", desc.doc());
+                    writeln!(definition, "pub enum {name} {{").unwrap();
+                    for variant in variants {
+                        let variant_name = pascal_case(variant.name());
+                        write_doc(&mut definition, "    ", variant.doc());
+                        match variant_content(variant) {
+                            None => {
+                                writeln!(definition, "    {variant_name},").unwrap();
+                            }
+                            Some(content) => match content.kind() {
+                                TypeKind::Struct(fields) if content.name() == variant.name() => {
+                                    writeln!(definition, "    {variant_name} {{").unwrap();
+                                    self.write_fields(&mut definition, "        ", fields);
+                                    writeln!(definition, "    }},").unwrap();
+                                }
+                                _ => {
+                                    let ty = self.reference(content);
+                                    writeln!(definition, "    {variant_name}({ty}),").unwrap();
+                                }
+                            },
+                        }
+                    }
+                    definition.push('}');
+                    self.definitions.define(index, definition);
+                }
+                name
+            }
+        }
+    }
+}
+
+/// Generate Rust type definitions that match a [`struct@TypeDescription`]
+///
+/// Every struct, enum and named wrapper type gets its own definition, with the documentation as
+/// doc comments. Integers and floats keep the size they are described with, maps become a
+/// `HashMap` and values of any shape a `serde_json::Value`. The output is meant as a starting
+/// point for hand-written types, e.g. on the side of a client, and does not include any derives.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, StructBuilder};
+///     use type_description::render::to_rust_source;
+///
+///     let server = StructBuilder::new("Server".into(), Some("A server"))
+///         .field("port", None, u16::as_type_description())
+///         .build();
+///
+///     assert_eq!(
+///         to_rust_source(&server),
+///         "/// A server\npub struct Server {\n    pub port: u16,\n}\n"
+///     );
+/// ```
+pub fn to_rust_source(desc: &TypeDescription) -> String {
+    let mut source = Source::default();
+    source.reference(desc);
+
+    source
+        .definitions
+        .into_iter()
+        .map(|definition| definition + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructBuilder, TypeDescription,
+        TypeEnumKind, TypeKind,
+    };
+
+    use super::to_rust_source;
+
+    #[test]
+    fn rust_nested_struct() {
+        let tls = StructBuilder::new("Tls".into(), Some("TLS settings"))
+            .field(
+                "cert",
+                Some("Path to the certificate"),
+                String::as_type_description(),
+            )
+            .build();
+        let level = TypeDescription::new(
+            "log-level".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Untagged,
+                variants: vec![
+                    EnumVariant::new(
+                        "Low",
+                        Some("Only errors"),
+                        EnumVariantRepresentation::String("low".into()),
+                    ),
+                    EnumVariant::new(
+                        "Custom",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(TypeDescription::new(
+                            "Custom".into(),
+                            TypeKind::Wrapped(Box::new(u8::as_type_description())),
+                            None,
+                        ))),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            None,
+        );
+        let server = StructBuilder::new("Server".into(), Some("A server\n\nListens on a port"))
+            .field("port", None, u16::as_type_description())
+            .field("type", None, String::as_type_description())
+            .field(
+                "limits",
+                None,
+                std::collections::HashMap::<String, f64>::as_type_description(),
+            )
+            .optional_field("tls", None, tls)
+            .field("level", None, Option::<Vec<bool>>::as_type_description())
+            .field("log", None, level)
+            .build();
+
+        assert_eq!(
+            to_rust_source(&server),
+            r#"/// A server
+///
+/// Listens on a port
+pub struct Server {
+    pub port: u16,
+    pub r#type: String,
+    pub limits: std::collections::HashMap<String, f64>,
+    pub tls: Option<Tls>,
+    pub level: Option<Vec<bool>>,
+    pub log: LogLevel,
+}
+
+/// TLS settings
+pub struct Tls {
+    /// Path to the certificate
+    pub cert: String,
+}
+
+pub enum LogLevel {
+    /// Only errors
+    Low,
+    Custom(u8),
+}
+"#
+        );
+    }
+}
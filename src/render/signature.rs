@@ -0,0 +1,133 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use super::variant_content;
+use crate::{TypeDescription, TypeKind};
+
+fn join(descs: &[TypeDescription], separator: &str) -> String {
+    descs
+        .iter()
+        .map(render_signature)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Render a [`struct@TypeDescription`] as a compact, single line signature
+///
+/// Structs are written as `Name { field: Type }`, arrays as `[Type]`, maps as `{Key: Value}`,
+/// tuples as `(A, B)` and enum variants as `A | B`. Optional values are marked with a trailing
+/// `?`, while other wrapped types are only shown by their name. No documentation is included,
+/// which makes this useful for tooltips and inline hints.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, render::render_signature};
+///
+///     assert_eq!(render_signature(&Vec::<Option<String>>::as_type_description()), "[String?]");
+/// ```
+pub fn render_signature(desc: &TypeDescription) -> String {
+    match desc.kind() {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes => desc.name().to_string(),
+        TypeKind::Unit => "()".to_string(),
+        TypeKind::Any => "Any".to_string(),
+        TypeKind::Ref(name) => name.clone(),
+        TypeKind::Wrapped(inner) if desc.is_optional() => {
+            format!("{}?", render_signature(inner))
+        }
+        TypeKind::Wrapped(_) => desc.name().to_string(),
+        TypeKind::Array(inner) => format!("[{}]", render_signature(inner)),
+        TypeKind::Tuple(elements) => format!("({})", join(elements, ", ")),
+        TypeKind::HashMap { key, value } => {
+            format!("{{{}: {}}}", render_signature(key), render_signature(value))
+        }
+        TypeKind::Struct(fields) if fields.is_empty() => format!("{} {{}}", desc.name()),
+        TypeKind::Struct(fields) => format!(
+            "{} {{ {} }}",
+            desc.name(),
+            fields
+                .iter()
+                .map(|field| format!(
+                    "{}{}: {}",
+                    field.name(),
+                    if field.optional() { "?" } else { "" },
+                    render_signature(field.kind())
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TypeKind::Enum { variants, .. } => variants
+            .iter()
+            .map(|variant| match variant_content(variant) {
+                None => variant.name().to_string(),
+                Some(content) => format!("{}({})", variant.name(), render_signature(content)),
+            })
+            .collect::<Vec<_>>()
+            .join(" | "),
+        TypeKind::OneOf(choices) => join(choices, " | "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{AsTypeDescription, StructBuilder};
+
+    use super::render_signature;
+
+    #[test]
+    fn signature_nested_struct() {
+        let tls = StructBuilder::new("Tls".into(), Some("TLS settings"))
+            .field(
+                "cert",
+                Some("Path to the certificate"),
+                String::as_type_description(),
+            )
+            .build();
+        let server = StructBuilder::new("Server".into(), Some("A server"))
+            .field("host", None, String::as_type_description())
+            .field("port", None, u16::as_type_description())
+            .field("tags", None, Vec::<String>::as_type_description())
+            .field(
+                "limits",
+                None,
+                HashMap::<String, u32>::as_type_description(),
+            )
+            .optional_field("tls", None, tls)
+            .field("timeout", None, Option::<f64>::as_type_description())
+            .build();
+
+        assert_eq!(
+            render_signature(&server),
+            "Server { host: String, port: Integer, tags: [String], limits: {String: Integer}, \
+             tls?: Tls { cert: String }, timeout: Float? }"
+        );
+    }
+
+    #[test]
+    fn signature_enum() {
+        assert_eq!(
+            render_signature(&Result::<bool, (u8, char)>::as_type_description()),
+            "Ok(Boolean) | Err((Integer, Char))"
+        );
+    }
+
+    #[test]
+    fn signature_renamed_optional() {
+        assert_eq!(
+            render_signature(
+                &Vec::<Option<u8>>::as_type_description().map_names(str::to_uppercase)
+            ),
+            "[INTEGER?]"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use crate::{join_path, EnumVariantRepresentation, TypeDescription, TypeKind};
+
+/// Get the documentation of a type, looking through wrapper types like `Option<T>`
+fn type_doc(desc: &TypeDescription) -> Option<&str> {
+    match (desc.doc(), desc.kind()) {
+        (Some(doc), _) => Some(doc),
+        (None, TypeKind::Wrapped(inner)) => type_doc(inner),
+        (None, _) => None,
+    }
+}
+
+fn collect_rows(
+    path: String,
+    doc: Option<&str>,
+    desc: &TypeDescription,
+    rows: &mut Vec<[String; 3]>,
+) {
+    let doc = doc
+        .or_else(|| type_doc(desc))
+        .unwrap_or_default()
+        .to_string();
+    rows.push([path.clone(), desc.kind().kind_name().to_string(), doc]);
+    collect_children(&path, desc, rows);
+}
+
+fn collect_children(path: &str, desc: &TypeDescription, rows: &mut Vec<[String; 3]>) {
+    match desc.kind() {
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes
+        | TypeKind::Unit
+        | TypeKind::Any
+        | TypeKind::Ref(_) => (),
+        // A wrapper shares the path, and thereby the row, with the type it wraps
+        TypeKind::Wrapped(inner) => collect_children(path, inner, rows),
+        TypeKind::Array(inner) => collect_rows(format!("{path}[]"), None, inner, rows),
+        TypeKind::Tuple(elements) | TypeKind::OneOf(elements) => {
+            for (idx, element) in elements.iter().enumerate() {
+                collect_rows(join_path(path, &idx.to_string()), None, element, rows);
+            }
+        }
+        TypeKind::HashMap { value, .. } => collect_rows(format!("{path}{{}}"), None, value, rows),
+        TypeKind::Struct(fields) => {
+            for field in fields {
+                collect_rows(
+                    join_path(path, field.name()),
+                    field.doc(),
+                    field.kind(),
+                    rows,
+                );
+            }
+        }
+        TypeKind::Enum { variants, .. } => {
+            for variant in variants {
+                if let EnumVariantRepresentation::Wrapped(inner) = variant.repr() {
+                    collect_rows(join_path(path, variant.name()), variant.doc(), inner, rows);
+                }
+            }
+        }
+    }
+}
+
+/// Flatten a [`struct@TypeDescription`] into rows of path, kind and documentation
+///
+/// Every part of the type gets a row, starting with the type itself at the empty path. Paths are
+/// written like the ones of [`TypeDescription::leaves`], e.g. `servers[].port`, and wrapper types
+/// like `Option<T>` share the row of the type they wrap. The kind is written as its
+/// [`TypeKind::kind_name`], e.g. `struct` or `integer`. Fields without documentation of their own
+/// use the documentation of their type.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, StructBuilder};
+///     use type_description::render::to_table_rows;
+///
+///     let server = StructBuilder::new("Server".into(), None)
+///         .field("port", Some("The port to listen on"), u16::as_type_description())
+///         .build();
+///
+///     assert_eq!(
+///         to_table_rows(&server)[1],
+///         ["port".to_string(), "integer".to_string(), "The port to listen on".to_string()]
+///     );
+/// ```
+pub fn to_table_rows(desc: &TypeDescription) -> Vec<[String; 3]> {
+    let mut rows = Vec::new();
+    collect_rows(String::new(), None, desc, &mut rows);
+    rows
+}
+
+fn csv_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// Render the rows of [`to_table_rows`] as CSV, with a `path,kind,doc` header
+///
+/// Cells containing commas, quotes or line breaks are quoted, so that multi-line documentation
+/// stays in a single cell when opened in a spreadsheet.
+pub fn to_csv(desc: &TypeDescription) -> String {
+    std::iter::once(["path", "kind", "doc"].map(String::from))
+        .chain(to_table_rows(desc))
+        .map(|row| row.map(|cell| csv_cell(&cell)).join(",") + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, StructBuilder};
+
+    use super::{to_csv, to_table_rows};
+
+    #[test]
+    fn table_nested_struct() {
+        let tls = StructBuilder::new("Tls".into(), Some("TLS settings"))
+            .field(
+                "cert",
+                Some("Path to the certificate, \"PEM\" encoded"),
+                String::as_type_description(),
+            )
+            .build();
+        let server = StructBuilder::new("Server".into(), Some("A server\n\nListens on a port"))
+            .field("port", Some("The port"), u16::as_type_description())
+            .field("tags", None, Vec::<String>::as_type_description())
+            .field("timeout", None, Option::<u32>::as_type_description())
+            .optional_field("tls", None, tls)
+            .build();
+
+        let rows = to_table_rows(&server)
+            .into_iter()
+            .map(|[path, name, doc]| format!("{path} | {name} | {doc}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                " | struct | A server\n\nListens on a port",
+                "port | integer | The port",
+                "tags | array | ",
+                "tags[] | string | An UTF-8 string",
+                "timeout | wrapped | An unsigned integer with 32 bits",
+                "tls | struct | TLS settings",
+                "tls.cert | string | Path to the certificate, \"PEM\" encoded",
+            ]
+        );
+
+        assert!(to_csv(&server).starts_with(
+            "path,kind,doc\n,struct,\"A server\n\nListens on a port\"\nport,integer,The port\n"
+        ));
+        assert!(to_csv(&server)
+            .ends_with("tls.cert,string,\"Path to the certificate, \"\"PEM\"\" encoded\"\n"));
+    }
+}
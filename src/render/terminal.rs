@@ -12,11 +12,51 @@ use pretty::Pretty;
 use pretty::RefDoc;
 use termimad::MadSkin;
 
+use super::{RenderOptions, TRUNCATED};
 use crate::EnumVariantRepresentation;
 use crate::TypeDescription;
 use crate::TypeEnumKind;
 use crate::TypeKind;
 
+/// Render a [`struct@TypeDescription`] to a String to print to a terminal with colors, wrapped
+/// at the given width
+///
+/// ## Example
+///
+/// ```rust
+/// use type_description::AsTypeDescription;
+///
+/// let input = Vec::<String>::as_type_description();
+///
+/// let output = type_description::render::render_to_terminal_string(&input, 80).unwrap();
+/// println!("{}", output);
+/// ```
+pub fn render_to_terminal_string(
+    desc: &TypeDescription,
+    width: usize,
+) -> Result<String, std::fmt::Error> {
+    render_to_terminal_string_with_options(
+        desc,
+        &RenderOptions {
+            terminal_width: width,
+            ..Default::default()
+        },
+    )
+}
+
+/// Render a [`struct@TypeDescription`] to a String to print to a terminal with colors, using the
+/// given [`RenderOptions`]
+pub fn render_to_terminal_string_with_options(
+    desc: &TypeDescription,
+    options: &RenderOptions,
+) -> Result<String, std::fmt::Error> {
+    let desc = options.prepare(desc);
+    let arena = Arena::new();
+    let mut output = String::new();
+    render_node(&desc, &arena, options, 0).render_fmt(options.terminal_width, &mut output)?;
+    Ok(output)
+}
+
 /// Get a [`RefDoc`](pretty::RefDoc) which can be converted into a String to print to a terminal
 /// with colors
 ///
@@ -34,6 +74,19 @@ use crate::TypeKind;
 /// println!("{}", output);
 /// ```
 pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -> RefDoc<'a> {
+    render_node(desc, arena, &RenderOptions::default(), 0)
+}
+
+fn render_node<'a>(
+    desc: &'a TypeDescription,
+    arena: &'a Arena<'a>,
+    options: &RenderOptions,
+    depth: usize,
+) -> RefDoc<'a> {
+    if options.too_deep(depth) {
+        return arena.text(TRUNCATED).into_doc();
+    }
+
     let mut doc = arena.nil();
 
     if !matches!(desc.kind(), TypeKind::Wrapped(_)) && desc.doc().is_none() {
@@ -45,13 +98,20 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
                 | TypeKind::Integer { .. }
                 | TypeKind::Float { .. }
                 | TypeKind::String
+                | TypeKind::Char
+                | TypeKind::Bytes
+                | TypeKind::Unit
+                | TypeKind::Any
+                | TypeKind::Ref(_)
                 | TypeKind::Wrapped(_)
                 | TypeKind::Array(_)
+                | TypeKind::Tuple(_)
+                | TypeKind::OneOf(_)
                 | TypeKind::HashMap { .. } => arena.nil(),
                 TypeKind::Struct(_) => {
                     arena.text(Color::Blue.dimmed().paint("[Table]").to_string())
                 }
-                TypeKind::Enum(_, _) => {
+                TypeKind::Enum { .. } => {
                     arena.text(Color::Green.dimmed().paint("[Enum]").to_string())
                 }
             })
@@ -77,7 +137,30 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
     }
 
     match desc.kind() {
-        TypeKind::Bool | TypeKind::Integer { .. } | TypeKind::Float { .. } | TypeKind::String => (),
+        TypeKind::Bool
+        | TypeKind::Integer { .. }
+        | TypeKind::Float { .. }
+        | TypeKind::String
+        | TypeKind::Char
+        | TypeKind::Bytes => (),
+        TypeKind::Unit => {
+            if desc.doc().is_some() {
+                doc = doc.append(arena.hardline());
+            }
+            doc = doc.append(Color::LightRed.paint("No value").to_string());
+        }
+        TypeKind::Any => {
+            if desc.doc().is_some() {
+                doc = doc.append(arena.hardline());
+            }
+            doc = doc.append(Color::LightRed.paint("Any value").to_string());
+        }
+        TypeKind::Ref(name) => {
+            doc = doc
+                .append(Color::LightRed.paint("Refers back to").to_string())
+                .append(arena.space())
+                .append(arena.text(name.clone()));
+        }
         TypeKind::Struct(stc) => {
             doc = doc
                 .append(arena.hardline())
@@ -85,7 +168,7 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
                 .append(arena.hardline())
                 .append(arena.intersperse(
                     stc.iter().map(|ev| {
-                        let member_name = ev.name();
+                        let member_name = ev.label();
                         let member_doc = ev.doc();
                         let member_conf = ev.kind();
                         let mut doc = arena.nil();
@@ -93,17 +176,28 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
                         if let Some(member_doc) = member_doc {
                             doc = doc.append(render_markdown(member_doc));
                         }
-                        doc.append(arena.text(Color::Blue.bold().paint(member_name).to_string()))
-                            .append(": ")
-                            .append(
-                                Pretty::pretty(render_to_terminal(member_conf, arena), arena)
-                                    .nest(4),
+                        doc = doc
+                            .append(arena.text(Color::Blue.bold().paint(member_name).to_string()));
+                        if ev.optional() {
+                            doc =
+                                doc.append(Color::White.dimmed().paint(" (optional)").to_string());
+                        }
+                        doc.append(": ").append(
+                            Pretty::pretty(
+                                render_node(member_conf, arena, options, depth + 1),
+                                arena,
                             )
+                            .nest(4),
+                        )
                     }),
                     Doc::hardline(),
                 ))
         }
-        TypeKind::Enum(enum_kind, variants) => {
+        TypeKind::Enum {
+            tagging: enum_kind,
+            variants,
+            non_exhaustive,
+        } => {
             doc = doc
                 .append(arena.hardline())
                 .append(Color::Green.paint("One of:").to_string())
@@ -121,14 +215,46 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
                             ))
                             .to_string(),
                     ),
+                    TypeEnumKind::Adjacent { tag, content } => arena.text(
+                        Color::White
+                            .dimmed()
+                            .paint(format!(
+                                "[Tagged with {}, content in {}]",
+                                Color::LightGreen
+                                    .italic()
+                                    .dimmed()
+                                    .paint(format!("'{}'", tag)),
+                                Color::LightGreen
+                                    .italic()
+                                    .dimmed()
+                                    .paint(format!("'{}'", content))
+                            ))
+                            .to_string(),
+                    ),
                     TypeEnumKind::Untagged => {
                         arena.text(Color::White.dimmed().paint("[Untagged]").to_string())
                     }
+                    TypeEnumKind::External => arena.text(
+                        Color::White
+                            .dimmed()
+                            .paint("[Externally tagged]")
+                            .to_string(),
+                    ),
+                })
+                .append(if *non_exhaustive {
+                    arena.space().append(
+                        Color::White
+                            .dimmed()
+                            .paint("(more variants may be added)")
+                            .to_string(),
+                    )
+                } else {
+                    arena.nil()
                 })
                 .append(arena.hardline())
                 .append(arena.intersperse(
                     variants.iter().map(|ev| {
-                        let member_name = ev.name();
+                        let member_name = ev.label();
                         let member_doc = ev.doc();
                         let member_conf = ev.repr();
                         arena.text("-").append(arena.space()).append({
@@ -159,7 +285,12 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
 
                                         EnumVariantRepresentation::Wrapped(member_conf) => arena
                                             .text(Color::LightRed.paint("Is a: ").to_string())
-                                            .append(render_to_terminal(member_conf, arena))
+                                            .append(render_node(
+                                                member_conf,
+                                                arena,
+                                                options,
+                                                depth + 1,
+                                            ))
                                             .into_doc(),
                                     },
                                     arena,
@@ -176,7 +307,36 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
             doc = doc
                 .append(Color::LightRed.paint("Many of:").to_string())
                 .append(arena.space())
-                .append(render_to_terminal(conf, arena));
+                .append(render_node(conf, arena, options, depth + 1));
+        }
+        TypeKind::Tuple(elements) => {
+            doc = doc
+                .append(Color::LightRed.paint("Tuple of:").to_string())
+                .append(arena.space())
+                .append(
+                    arena.intersperse(
+                        elements
+                            .iter()
+                            .map(|element| render_node(element, arena, options, depth + 1)),
+                        arena.text(", "),
+                    ),
+                );
+        }
+        TypeKind::OneOf(choices) => {
+            doc = doc
+                .append(arena.hardline())
+                .append(Color::Green.paint("One of:").to_string())
+                .append(arena.hardline())
+                .append(arena.intersperse(
+                    choices.iter().map(|choice| {
+                        arena
+                            .text("-")
+                            .append(arena.space())
+                            .append(render_node(choice, arena, options, depth + 1))
+                            .nest(2)
+                    }),
+                    Doc::hardline(),
+                ));
         }
         TypeKind::HashMap { key, value } => {
             doc = doc
@@ -189,9 +349,79 @@ pub fn render_to_terminal<'a>(desc: &'a TypeDescription, arena: &'a Arena<'a>) -
                 .append(value.name());
         }
         TypeKind::Wrapped(conf) => {
-            doc = doc.append(render_to_terminal(conf, arena));
+            doc = doc.append(render_node(conf, arena, options, depth + 1));
         }
     };
 
     doc.into_doc()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsTypeDescription, StructField, TypeDescription, TypeKind};
+
+    use super::{render_to_terminal_string, render_to_terminal_string_with_options};
+    use crate::render::RenderOptions;
+
+    fn strip_ansi(text: &str) -> String {
+        let mut stripped = String::new();
+        let mut chars = text.chars();
+        while let Some(ch) = chars.next() {
+            if ch == '\x1b' {
+                for ch in chars.by_ref() {
+                    if ch.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            } else {
+                stripped.push(ch);
+            }
+        }
+        stripped
+    }
+
+    #[test]
+    fn render_struct_to_terminal() {
+        let desc = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![
+                StructField::new("port", None, u16::as_type_description(), false),
+                StructField::new("hosts", None, Vec::<String>::as_type_description(), true),
+            ]),
+            None,
+        );
+
+        let output = render_to_terminal_string(&desc, 80).unwrap();
+        assert!(output.contains('\x1b'));
+
+        let plain = strip_ansi(&output);
+        assert!(plain.starts_with("Server [Table]"));
+        assert!(plain.contains("[Members]"));
+        assert!(plain.contains("port: An unsigned integer with 16 bits"));
+        assert!(plain.contains("hosts (optional): Array of 'String's"));
+        assert!(plain.contains("    Many of: An UTF-8 string"));
+    }
+
+    #[test]
+    fn render_max_depth_to_terminal() {
+        let desc = TypeDescription::new(
+            "Server".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "hosts",
+                None,
+                Vec::<String>::as_type_description(),
+                false,
+            )]),
+            None,
+        );
+        let options = RenderOptions {
+            max_depth: Some(1),
+            ..Default::default()
+        };
+
+        let plain = strip_ansi(&render_to_terminal_string_with_options(&desc, &options).unwrap());
+        assert!(plain.contains("hosts: Array of 'String's"));
+        assert!(plain.contains("Many of: … (nested, truncated)"));
+        assert!(!plain.contains("An UTF-8 string"));
+    }
+}
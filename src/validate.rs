@@ -0,0 +1,535 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use serde_json::Value;
+
+use crate::{
+    join_path, Constraint, EnumVariant, EnumVariantRepresentation, Sign, TypeDescription,
+    TypeEnumKind, TypeKind,
+};
+
+/// A reason a value does not match a [`struct@TypeDescription`]
+///
+/// Every error carries the path to where it was found, with struct fields separated by `.`,
+/// array and tuple elements as `[0]` and map values as `{key}`. The root has the empty path.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum ValidationError {
+    /// The value is of a different type than described
+    WrongType {
+        /// Where the error was found
+        path: String,
+        /// The [`TypeKind::kind_name`] of the described type
+        expected: &'static str,
+        /// The type of the value, like `"number"` or `"object"`
+        found: &'static str,
+    },
+    /// The integer does not fit into the described number of bits
+    OutOfRange {
+        /// Where the error was found
+        path: String,
+    },
+    /// The tuple has a different number of elements than described
+    WrongLength {
+        /// Where the error was found
+        path: String,
+        /// The described number of elements
+        expected: usize,
+        /// The number of elements of the value
+        found: usize,
+    },
+    /// A field that is not optional is missing, this includes the tag of a tagged enum
+    MissingField {
+        /// The path of the struct
+        path: String,
+        /// The name of the missing field
+        field: String,
+    },
+    /// The value names an enum variant that does not exist
+    UnknownVariant {
+        /// Where the error was found
+        path: String,
+        /// The name of the variant
+        variant: String,
+    },
+    /// The value matches none of the variants of an untagged enum, or none of the choices of a
+    /// [`TypeKind::OneOf`]
+    NoMatch {
+        /// Where the error was found
+        path: String,
+    },
+    /// The value does not fulfill a constraint
+    ConstraintViolated {
+        /// Where the error was found
+        path: String,
+        /// The constraint that is not fulfilled
+        constraint: Constraint,
+    },
+}
+
+impl ValidationError {
+    /// Get the path to where the error was found.
+    pub fn path(&self) -> &str {
+        match self {
+            ValidationError::WrongType { path, .. }
+            | ValidationError::OutOfRange { path }
+            | ValidationError::WrongLength { path, .. }
+            | ValidationError::MissingField { path, .. }
+            | ValidationError::UnknownVariant { path, .. }
+            | ValidationError::NoMatch { path }
+            | ValidationError::ConstraintViolated { path, .. } => path,
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "at '{}': ", self.path())?;
+        match self {
+            ValidationError::WrongType {
+                expected, found, ..
+            } => write!(f, "expected {expected}, found {found}"),
+            ValidationError::OutOfRange { .. } => write!(f, "the integer is out of range"),
+            ValidationError::WrongLength {
+                expected, found, ..
+            } => write!(f, "expected {expected} elements, found {found}"),
+            ValidationError::MissingField { field, .. } => {
+                write!(f, "the field `{field}` is missing")
+            }
+            ValidationError::UnknownVariant { variant, .. } => {
+                write!(f, "there is no variant `{variant}`")
+            }
+            ValidationError::NoMatch { .. } => write!(f, "no variant matches the value"),
+            ValidationError::ConstraintViolated { constraint, .. } => {
+                write!(f, "the value is not {constraint}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn integer_fits(value: &Value, sign: &Sign, size: u8) -> bool {
+    match (sign, value.as_i64(), value.as_u64()) {
+        (Sign::Unsigned, _, Some(value)) => size >= 64 || value < 1 << size,
+        (Sign::Unsigned, _, None) => false,
+        (Sign::Signed, Some(value), _) => {
+            size >= 64 || (-(1 << (size - 1))..1 << (size - 1)).contains(&value)
+        }
+        // Only larger than an `i64` can hold
+        (Sign::Signed, None, Some(_)) => size > 64,
+        (Sign::Signed, None, None) => false,
+    }
+}
+
+fn check_constraints(
+    path: &str,
+    desc: &TypeDescription,
+    value: &Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    let len = match value {
+        Value::String(string) => Some(string.chars().count()),
+        Value::Array(elements) => Some(elements.len()),
+        Value::Object(entries) => Some(entries.len()),
+        _ => None,
+    };
+
+    for constraint in desc.constraints() {
+        let fulfilled = match (constraint, value.as_f64(), len) {
+            (Constraint::Min(min), Some(number), _) => number >= *min as f64,
+            (Constraint::Max(max), Some(number), _) => number <= *max as f64,
+            (Constraint::MinLen(min), _, Some(len)) => len >= *min,
+            (Constraint::MaxLen(max), _, Some(len)) => len <= *max,
+            // Checking patterns would need a regex engine
+            _ => true,
+        };
+        if !fulfilled {
+            errors.push(ValidationError::ConstraintViolated {
+                path: path.to_string(),
+                constraint: constraint.clone(),
+            });
+        }
+    }
+}
+
+struct Validator<'d> {
+    /// The types that contain the current one, for resolving [`TypeKind::Ref`]s
+    ancestors: Vec<&'d TypeDescription>,
+    errors: Vec<ValidationError>,
+}
+
+impl<'d> Validator<'d> {
+    /// Whether the value matches the type, without recording any errors
+    fn matches(&mut self, path: &str, desc: &'d TypeDescription, value: &Value) -> bool {
+        let errors = std::mem::take(&mut self.errors);
+        self.validate(path, desc, value);
+        std::mem::replace(&mut self.errors, errors).is_empty()
+    }
+
+    fn wrong_type(&mut self, path: &str, desc: &TypeDescription, value: &Value) {
+        self.errors.push(ValidationError::WrongType {
+            path: path.to_string(),
+            expected: desc.kind().kind_name(),
+            found: json_type(value),
+        });
+    }
+
+    fn validate(&mut self, path: &str, desc: &'d TypeDescription, value: &Value) {
+        check_constraints(path, desc, value, &mut self.errors);
+
+        self.ancestors.push(desc);
+        match (desc.kind(), value) {
+            (TypeKind::Any, _)
+            | (TypeKind::Unit, Value::Null)
+            | (TypeKind::Bool, Value::Bool(_))
+            | (TypeKind::Float { .. }, Value::Number(_))
+            | (TypeKind::String | TypeKind::Bytes, Value::String(_)) => (),
+            (TypeKind::Char, Value::String(string)) if string.chars().count() == 1 => (),
+            (TypeKind::Integer { sign, size }, Value::Number(number)) => {
+                if number.is_f64() {
+                    self.wrong_type(path, desc, value);
+                } else if !integer_fits(value, sign, *size) {
+                    self.errors.push(ValidationError::OutOfRange {
+                        path: path.to_string(),
+                    });
+                }
+            }
+            (TypeKind::Wrapped(_), Value::Null) if desc.is_optional() => (),
+            (TypeKind::Wrapped(inner), _) => self.validate(path, inner, value),
+            (TypeKind::Array(inner), Value::Array(elements)) => {
+                for (idx, element) in elements.iter().enumerate() {
+                    self.validate(&format!("{path}[{idx}]"), inner, element);
+                }
+            }
+            (TypeKind::Tuple(types), Value::Array(elements)) => {
+                if types.len() != elements.len() {
+                    self.errors.push(ValidationError::WrongLength {
+                        path: path.to_string(),
+                        expected: types.len(),
+                        found: elements.len(),
+                    });
+                }
+                for (idx, (ty, element)) in types.iter().zip(elements).enumerate() {
+                    self.validate(&format!("{path}[{idx}]"), ty, element);
+                }
+            }
+            (TypeKind::HashMap { value: ty, .. }, Value::Object(entries)) => {
+                for (key, element) in entries {
+                    self.validate(&format!("{path}{{{key}}}"), ty, element);
+                }
+            }
+            (TypeKind::Struct(fields), Value::Object(entries)) => {
+                for field in fields {
+                    let kind = field.kind();
+                    match entries.get(field.name()) {
+                        Some(Value::Null) if field.optional() => (),
+                        Some(entry) => self.validate(&join_path(path, field.name()), kind, entry),
+                        None if field.optional() => (),
+                        // serde fills in `None` for a missing `Option`
+                        None if kind.is_optional() => (),
+                        None => self.errors.push(ValidationError::MissingField {
+                            path: path.to_string(),
+                            field: field.name().to_string(),
+                        }),
+                    }
+                }
+            }
+            (
+                TypeKind::Enum {
+                    tagging: tag_kind,
+                    variants,
+                    ..
+                },
+                _,
+            ) => self.validate_enum(path, tag_kind, variants, value),
+            (TypeKind::OneOf(choices), _) => {
+                if !choices
+                    .iter()
+                    .any(|choice| self.matches(path, choice, value))
+                {
+                    self.errors.push(ValidationError::NoMatch {
+                        path: path.to_string(),
+                    });
+                }
+            }
+            (TypeKind::Ref(name), _) => {
+                let target = self
+                    .ancestors
+                    .iter()
+                    .rev()
+                    .find(|ancestor| ancestor.name() == name)
+                    .copied();
+                if let Some(target) = target {
+                    self.validate(path, target, value);
+                }
+            }
+            _ => self.wrong_type(path, desc, value),
+        }
+        self.ancestors.pop();
+    }
+
+    fn validate_enum(
+        &mut self,
+        path: &str,
+        tag_kind: &TypeEnumKind,
+        variants: &'d [EnumVariant],
+        value: &Value,
+    ) {
+        // The name a variant is serialized with, which can differ from its label
+        let find = |tag: &str| {
+            variants.iter().find(|variant| match variant.repr() {
+                EnumVariantRepresentation::String(repr) => repr == tag,
+                EnumVariantRepresentation::Wrapped(_) => variant.name() == tag,
+            })
+        };
+
+        let tag_field = match tag_kind {
+            TypeEnumKind::Untagged => {
+                let matched = variants.iter().any(|variant| match variant.repr() {
+                    EnumVariantRepresentation::String(repr) => value.as_str() == Some(repr),
+                    EnumVariantRepresentation::Wrapped(inner) => self.matches(path, inner, value),
+                });
+                if !matched {
+                    self.errors.push(ValidationError::NoMatch {
+                        path: path.to_string(),
+                    });
+                }
+                return;
+            }
+            TypeEnumKind::External => None,
+            TypeEnumKind::Tagged(tag) | TypeEnumKind::Adjacent { tag, .. } => Some(tag),
+        };
+
+        let (tag, content) = match (tag_field, value) {
+            (None, Value::String(tag)) => (tag, None),
+            (None, Value::Object(entries)) if entries.len() == 1 => {
+                let (tag, content) = entries.iter().next().unwrap();
+                (tag, Some(content))
+            }
+            (Some(field), Value::Object(entries)) => {
+                let tag = match entries.get(field.as_ref()) {
+                    Some(Value::String(tag)) => tag,
+                    Some(tag) => {
+                        self.errors.push(ValidationError::WrongType {
+                            path: join_path(path, field),
+                            expected: TypeKind::String.kind_name(),
+                            found: json_type(tag),
+                        });
+                        return;
+                    }
+                    None => {
+                        self.errors.push(ValidationError::MissingField {
+                            path: path.to_string(),
+                            field: field.to_string(),
+                        });
+                        return;
+                    }
+                };
+                let content = match tag_kind {
+                    TypeEnumKind::Adjacent { content, .. } => entries.get(content.as_ref()),
+                    // The fields of the variant are next to the tag
+                    _ => Some(value),
+                };
+                (tag, content)
+            }
+            _ => {
+                self.errors.push(ValidationError::WrongType {
+                    path: path.to_string(),
+                    expected: "enum",
+                    found: json_type(value),
+                });
+                return;
+            }
+        };
+
+        match (find(tag).map(EnumVariant::repr), content) {
+            (None, _) => self.errors.push(ValidationError::UnknownVariant {
+                path: path.to_string(),
+                variant: tag.to_string(),
+            }),
+            (Some(EnumVariantRepresentation::Wrapped(inner)), Some(content)) => {
+                self.validate(path, inner, content)
+            }
+            (Some(EnumVariantRepresentation::Wrapped(inner)), None) => {
+                self.validate(path, inner, &Value::Null)
+            }
+            (Some(EnumVariantRepresentation::String(_)), _) => (),
+        }
+    }
+}
+
+impl TypeDescription {
+    /// Check whether a value, e.g. a parsed configuration file, matches the description
+    ///
+    /// All errors are collected instead of stopping at the first one. Fields that are not
+    /// described are ignored, just like serde does by default, and [`Constraint::Pattern`]s are
+    /// not checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///     use type_description::{AsTypeDescription, StructBuilder};
+    ///
+    ///     let server = StructBuilder::new("Server".into(), None)
+    ///         .field("port", None, u16::as_type_description())
+    ///         .build();
+    ///
+    ///     assert!(server.validate_value(&serde_json::json!({ "port": 8080 })).is_ok());
+    ///     assert!(server.validate_value(&serde_json::json!({ "port": -1 })).is_err());
+    /// ```
+    pub fn validate_value(&self, value: &Value) -> Result<(), Vec<ValidationError>> {
+        let mut validator = Validator {
+            ancestors: vec![],
+            errors: vec![],
+        };
+        validator.validate("", self, value);
+
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use crate::{
+        AsTypeDescription, EnumVariant, EnumVariantRepresentation, StructBuilder, TypeDescription,
+        TypeEnumKind, TypeKind,
+    };
+
+    use super::ValidationError;
+
+    fn server() -> TypeDescription {
+        let storage = TypeDescription::new(
+            "Storage".into(),
+            TypeKind::Enum {
+                tagging: TypeEnumKind::Tagged("type".into()),
+                variants: vec![
+                    EnumVariant::new(
+                        "memory",
+                        None,
+                        EnumVariantRepresentation::String("memory".into()),
+                    ),
+                    EnumVariant::new(
+                        "disk",
+                        None,
+                        EnumVariantRepresentation::Wrapped(Box::new(
+                            StructBuilder::new("disk".into(), None)
+                                .field("path", None, String::as_type_description())
+                                .build(),
+                        )),
+                    ),
+                ],
+                non_exhaustive: false,
+            },
+            None,
+        );
+
+        StructBuilder::new("Server".into(), None)
+            .field("port", None, u16::as_type_description())
+            .field("hosts", None, Vec::<String>::as_type_description())
+            .field(
+                "limits",
+                None,
+                HashMap::<String, f64>::as_type_description(),
+            )
+            .field("timeout", None, Option::<u32>::as_type_description())
+            .optional_field("storage", None, storage)
+            .build()
+    }
+
+    #[test]
+    fn validate_valid_value() {
+        let value = json!({
+            "port": 8080,
+            "hosts": ["localhost"],
+            "limits": { "cpu": 0.5 },
+            "storage": { "type": "disk", "path": "/var/lib" },
+            "unrelated": true,
+        });
+
+        assert_eq!(server().validate_value(&value), Ok(()));
+    }
+
+    #[test]
+    fn validate_invalid_values() {
+        let value = json!({
+            "port": "8080",
+            "hosts": ["localhost", 1],
+            "limits": { "cpu": "half" },
+            "timeout": -1,
+        });
+
+        assert_eq!(
+            server().validate_value(&value),
+            Err(vec![
+                ValidationError::WrongType {
+                    path: "port".into(),
+                    expected: "integer",
+                    found: "string",
+                },
+                ValidationError::WrongType {
+                    path: "hosts[1]".into(),
+                    expected: "string",
+                    found: "number",
+                },
+                ValidationError::WrongType {
+                    path: "limits{cpu}".into(),
+                    expected: "float",
+                    found: "string",
+                },
+                ValidationError::OutOfRange {
+                    path: "timeout".into()
+                },
+            ])
+        );
+
+        assert_eq!(
+            server().validate_value(&json!({ "hosts": [], "limits": {} })),
+            Err(vec![ValidationError::MissingField {
+                path: "".into(),
+                field: "port".into(),
+            }])
+        );
+
+        let errors = server()
+            .validate_value(&json!({
+                "port": 1,
+                "hosts": [],
+                "limits": {},
+                "storage": { "type": "cloud" },
+            }))
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            [ValidationError::UnknownVariant {
+                path: "storage".into(),
+                variant: "cloud".into(),
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "at 'storage': there is no variant `cloud`"
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use crate::{
+    EnumVariant, EnumVariantRepresentation, Sign, StructField, TypeDescription, TypeEnumKind,
+    TypeKind,
+};
+
+/// A visitor over the nodes of a [`struct@TypeDescription`] tree
+///
+/// All methods do nothing by default, so implementors only need to override the ones they are
+/// interested in. Use [`walk`] to drive the visitor through a tree.
+pub trait TypeDescriptionVisitor {
+    /// Called for every visited description, before the kind specific method
+    fn visit_description(&mut self, _desc: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Bool`]
+    fn visit_bool(&mut self, _desc: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Integer`]
+    fn visit_integer(&mut self, _desc: &TypeDescription, _sign: &Sign, _size: u8) {}
+
+    /// Called for [`TypeKind::Float`]
+    fn visit_float(&mut self, _desc: &TypeDescription, _size: u8) {}
+
+    /// Called for [`TypeKind::String`]
+    fn visit_string(&mut self, _desc: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Char`]
+    fn visit_char(&mut self, _desc: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Bytes`]
+    fn visit_bytes(&mut self, _desc: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Unit`]
+    fn visit_unit(&mut self, _desc: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Any`]
+    fn visit_any(&mut self, _desc: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Ref`]
+    fn visit_ref(&mut self, _desc: &TypeDescription, _name: &str) {}
+
+    /// Called for [`TypeKind::Wrapped`], before the wrapped description is visited
+    fn visit_wrapped(&mut self, _desc: &TypeDescription, _inner: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Array`], before the element description is visited
+    fn visit_array(&mut self, _desc: &TypeDescription, _inner: &TypeDescription) {}
+
+    /// Called for [`TypeKind::Tuple`], before the element descriptions are visited
+    fn visit_tuple(&mut self, _desc: &TypeDescription, _elements: &[TypeDescription]) {}
+
+    /// Called for [`TypeKind::HashMap`], before the key and value descriptions are visited
+    fn visit_hashmap(
+        &mut self,
+        _desc: &TypeDescription,
+        _key: &TypeDescription,
+        _value: &TypeDescription,
+    ) {
+    }
+
+    /// Called for [`TypeKind::Struct`], before the field descriptions are visited
+    fn visit_struct(&mut self, _desc: &TypeDescription, _fields: &[StructField]) {}
+
+    /// Called for [`TypeKind::OneOf`], before the choice descriptions are visited
+    fn visit_one_of(&mut self, _desc: &TypeDescription, _choices: &[TypeDescription]) {}
+
+    /// Called for [`TypeKind::Enum`], before the wrapped variant descriptions are visited
+    fn visit_enum(
+        &mut self,
+        _desc: &TypeDescription,
+        _kind: &TypeEnumKind,
+        _variants: &[EnumVariant],
+    ) {
+    }
+}
+
+/// Walk through a [`struct@TypeDescription`] tree depth-first, calling the visitor for every node
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::AsTypeDescription;
+///     use type_description::TypeDescription;
+///     use type_description::visit::{walk, TypeDescriptionVisitor};
+///
+///     #[derive(Default)]
+///     struct CountNodes(usize);
+///
+///     impl TypeDescriptionVisitor for CountNodes {
+///         fn visit_description(&mut self, _desc: &TypeDescription) {
+///             self.0 += 1;
+///         }
+///     }
+///
+///     let mut counter = CountNodes::default();
+///     walk(&Vec::<String>::as_type_description(), &mut counter);
+///     assert_eq!(counter.0, 2);
+/// ```
+pub fn walk(desc: &TypeDescription, visitor: &mut impl TypeDescriptionVisitor) {
+    visitor.visit_description(desc);
+
+    match desc.kind() {
+        TypeKind::Bool => visitor.visit_bool(desc),
+        TypeKind::Integer { sign, size } => visitor.visit_integer(desc, sign, *size),
+        TypeKind::Float { size } => visitor.visit_float(desc, *size),
+        TypeKind::String => visitor.visit_string(desc),
+        TypeKind::Char => visitor.visit_char(desc),
+        TypeKind::Bytes => visitor.visit_bytes(desc),
+        TypeKind::Unit => visitor.visit_unit(desc),
+        TypeKind::Any => visitor.visit_any(desc),
+        TypeKind::Ref(name) => visitor.visit_ref(desc, name),
+        TypeKind::Wrapped(inner) => {
+            visitor.visit_wrapped(desc, inner);
+            walk(inner, visitor);
+        }
+        TypeKind::Array(inner) => {
+            visitor.visit_array(desc, inner);
+            walk(inner, visitor);
+        }
+        TypeKind::Tuple(elements) => {
+            visitor.visit_tuple(desc, elements);
+            for element in elements {
+                walk(element, visitor);
+            }
+        }
+        TypeKind::OneOf(choices) => {
+            visitor.visit_one_of(desc, choices);
+            for choice in choices {
+                walk(choice, visitor);
+            }
+        }
+        TypeKind::HashMap { key, value } => {
+            visitor.visit_hashmap(desc, key, value);
+            walk(key, visitor);
+            walk(value, visitor);
+        }
+        TypeKind::Struct(fields) => {
+            visitor.visit_struct(desc, fields);
+            for field in fields {
+                walk(field.kind(), visitor);
+            }
+        }
+        TypeKind::Enum {
+            tagging: kind,
+            variants,
+            ..
+        } => {
+            visitor.visit_enum(desc, kind, variants);
+            for variant in variants {
+                if let EnumVariantRepresentation::Wrapped(inner) = variant.repr() {
+                    walk(inner, visitor);
+                }
+            }
+        }
+    }
+}
+
+/// Walk through a [`struct@TypeDescription`] tree depth-first, calling `f` on every node to modify
+/// it in place
+///
+/// A node is passed to `f` before its children, so changes to a node's kind are reflected in
+/// which children are walked afterwards.
+///
+/// # Example
+///
+/// ```rust
+///     use type_description::{AsTypeDescription, Constraint, TypeKind};
+///     use type_description::visit::walk_mut;
+///
+///     let mut desc = Vec::<String>::as_type_description();
+///     walk_mut(&mut desc, &mut |desc| {
+///         if desc.kind() == &TypeKind::String {
+///             *desc = desc.clone().with_constraints(vec![Constraint::MinLen(1)]);
+///         }
+///     });
+///
+///     let TypeKind::Array(inner) = desc.kind() else { unreachable!() };
+///     assert_eq!(inner.constraints(), [Constraint::MinLen(1)]);
+/// ```
+pub fn walk_mut(desc: &mut TypeDescription, f: &mut impl FnMut(&mut TypeDescription)) {
+    f(desc);
+
+    for child in desc.children_mut() {
+        walk_mut(child, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{AsTypeDescription, Sign, StructField, TypeDescription, TypeKind};
+
+    use super::{walk, walk_mut, TypeDescriptionVisitor};
+
+    #[derive(Default)]
+    struct CountIntegers(usize);
+
+    impl TypeDescriptionVisitor for CountIntegers {
+        fn visit_integer(&mut self, _desc: &TypeDescription, _sign: &Sign, _size: u8) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn count_integer_leaves() {
+        let desc = TypeDescription::new(
+            "Config".into(),
+            TypeKind::Struct(vec![
+                StructField::new("port", None, u16::as_type_description(), false),
+                StructField::new("name", None, String::as_type_description(), false),
+                StructField::new(
+                    "limits",
+                    None,
+                    HashMap::<u8, Vec<(i64, f32)>>::as_type_description(),
+                    false,
+                ),
+                StructField::new("retries", None, Option::<u32>::as_type_description(), true),
+            ]),
+            None,
+        );
+
+        let mut counter = CountIntegers::default();
+        walk(&desc, &mut counter);
+        assert_eq!(counter.0, 4);
+    }
+
+    #[test]
+    fn uppercase_names() {
+        let mut desc = TypeDescription::new(
+            "Config".into(),
+            TypeKind::Struct(vec![StructField::new(
+                "limits",
+                None,
+                HashMap::<String, Vec<u8>>::as_type_description(),
+                false,
+            )]),
+            None,
+        );
+
+        let mut visited = 0;
+        walk_mut(&mut desc, &mut |desc| {
+            visited += 1;
+            desc.set_name(desc.name().to_uppercase());
+        });
+
+        assert_eq!(visited, 5);
+        assert_eq!(desc.name(), "CONFIG");
+        let TypeKind::Struct(fields) = desc.kind() else {
+            panic!("Should be a struct");
+        };
+        let TypeKind::HashMap { key, value } = fields[0].kind().kind() else {
+            panic!("Should be a map");
+        };
+        assert_eq!(
+            fields[0].kind().name(),
+            "TABLE OF 'STRING => ARRAY OF 'INTEGER'S'"
+        );
+        assert_eq!(key.name(), "STRING");
+        assert_eq!(value.name(), "ARRAY OF 'INTEGER'S");
+    }
+}
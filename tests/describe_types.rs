@@ -0,0 +1,117 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![cfg(feature = "bin")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use type_description::AsTypeDescription;
+
+fn describe(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_describe_types"))
+        .arg("describe")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+fn input() -> String {
+    serde_json::to_string(&std::collections::HashMap::<String, bool>::as_type_description())
+        .unwrap()
+}
+
+#[test]
+fn describe_from_stdin() {
+    let output = describe(&["--format", "plain"], &input());
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Table of 'String => Boolean'\n  key: String - An UTF-8 string\n  value: Boolean - A boolean\n"
+    );
+}
+
+#[test]
+fn describe_from_file() {
+    let path = std::env::temp_dir().join(format!("describe_types_{}.json", std::process::id()));
+    std::fs::write(&path, input()).unwrap();
+
+    let output = describe(&["--format", "json-schema", path.to_str().unwrap()], "");
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["additionalProperties"]["type"], "boolean");
+}
+
+#[test]
+fn describe_markdown() {
+    let output = describe(&["--format", "markdown"], &input());
+
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .starts_with("# Table of 'String => Boolean'"));
+}
+
+#[test]
+fn describe_signature() {
+    let output = describe(&["--format", "signature"], &input());
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{String: Boolean}\n"
+    );
+}
+
+#[test]
+fn describe_invalid_input() {
+    let output = describe(&["--format", "plain"], "not json");
+
+    assert!(!output.status.success());
+}
+
+#[test]
+fn deprecated_output_format() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_describe_types"))
+        .args(["--output-format", "plain"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input().as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("describe --format plain"));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Table of 'String => Boolean'\n  key: String - An UTF-8 string\n  value: Boolean - A boolean\n"
+    );
+}
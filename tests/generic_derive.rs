@@ -0,0 +1,109 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use type_description::{AsTypeDescription, TypeDescription, TypeKind};
+
+#[derive(Debug, TypeDescription)]
+struct Wrapper<T> {
+    /// The wrapped value
+    inner: T,
+}
+
+#[derive(Debug, TypeDescription)]
+struct Named<'a, K: AsTypeDescription, V>
+where
+    V: AsTypeDescription + Clone,
+{
+    name: &'a std::path::Path,
+    entries: std::collections::HashMap<K, V>,
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(untagged)]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[test]
+fn check_generic_struct() {
+    let desc = Wrapper::<u16>::as_type_description();
+
+    assert_eq!(desc.name(), "Wrapper");
+    match desc.kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].kind(), &u16::as_type_description());
+        }
+        _ => panic!("Should be a struct"),
+    }
+
+    match Named::<'static, String, bool>::as_type_description().kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(
+                fields[1].kind(),
+                &std::collections::HashMap::<String, bool>::as_type_description()
+            );
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[test]
+fn check_generic_enum() {
+    match Either::<String, Vec<u8>>::as_type_description().kind() {
+        TypeKind::Enum { variants, .. } => {
+            assert_eq!(variants.len(), 2);
+            assert_eq!(variants[0].name(), "Left");
+        }
+        _ => panic!("Should be an enum"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Id<T> {
+    value: u64,
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// A type parameter that is never described
+struct Opaque;
+
+#[derive(Debug, TypeDescription)]
+struct Handle<T, U> {
+    id: u64,
+    #[description(skip)]
+    cache: Option<T>,
+    _marker: std::marker::PhantomData<U>,
+}
+
+#[test]
+fn check_phantom_data_is_skipped() {
+    match Id::<Opaque>::as_type_description().kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].name(), "value");
+        }
+        _ => panic!("Should be a struct"),
+    }
+
+    assert_eq!(
+        std::marker::PhantomData::<str>::as_type_description().kind(),
+        &TypeKind::Unit
+    );
+}
+
+#[test]
+fn check_unused_parameters_are_unbounded() {
+    match Handle::<Opaque, Opaque>::as_type_description().kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].name(), "id");
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
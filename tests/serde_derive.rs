@@ -75,3 +75,461 @@ fn check_type_description() {
 
     println!("{:#?}", desc);
 }
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct Listener {
+    #[serde(rename = "bind-address")]
+    bind_address: String,
+    port: u16,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[description(use_serde)]
+#[description(tag = "type")]
+enum Storage {
+    #[serde(rename = "memory")]
+    InMemory,
+    #[serde(rename = "disk")]
+    #[description(rename = "On disk")]
+    OnDisk {
+        #[serde(rename = "directory")]
+        path: String,
+    },
+}
+
+#[test]
+fn check_renamed_names() {
+    let desc = Listener::as_type_description();
+
+    match desc.kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].name(), "bind-address");
+            assert_eq!(fields[1].name(), "port");
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[test]
+fn check_renamed_variants() {
+    let desc = Storage::as_type_description();
+
+    match desc.kind() {
+        type_description::TypeKind::Enum { variants, .. } => {
+            assert_eq!(variants[0].name(), "memory");
+            assert!(matches!(
+                variants[0].repr(),
+                type_description::EnumVariantRepresentation::String(repr) if repr == "memory"
+            ));
+
+            assert_eq!(variants[1].name(), "disk");
+            match variants[1].repr() {
+                type_description::EnumVariantRepresentation::Wrapped(inner) => match inner.kind() {
+                    type_description::TypeKind::Struct(fields) => {
+                        assert_eq!(fields[0].name(), "directory");
+                    }
+                    _ => panic!("Should be a struct"),
+                },
+                _ => panic!("Should be wrapped"),
+            }
+        }
+        _ => panic!("Should be an enum"),
+    }
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[description(use_serde)]
+struct KebabConfig {
+    max_connections: u32,
+    #[serde(rename = "timeout")]
+    request_timeout: u32,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[description(use_serde)]
+struct CamelConfig {
+    max_connections: u32,
+    log_level: LogLevel,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[description(use_serde)]
+#[description(untagged)]
+enum LogLevel {
+    VeryVerbose,
+    Info,
+}
+
+#[test]
+fn check_rename_all() {
+    let names = |desc: type_description::TypeDescription| match desc.kind() {
+        type_description::TypeKind::Struct(fields) => fields
+            .iter()
+            .map(|f| f.name().to_string())
+            .collect::<Vec<_>>(),
+        type_description::TypeKind::Enum { variants, .. } => variants
+            .iter()
+            .map(|v| v.name().to_string())
+            .collect::<Vec<_>>(),
+        _ => panic!("Should be a struct or enum"),
+    };
+
+    assert_eq!(
+        names(KebabConfig::as_type_description()),
+        ["max-connections", "timeout"]
+    );
+    assert_eq!(
+        names(CamelConfig::as_type_description()),
+        ["maxConnections", "logLevel"]
+    );
+    assert_eq!(
+        names(LogLevel::as_type_description()),
+        ["VERY_VERBOSE", "INFO"]
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn check_renamed_variants_validate() {
+    let desc = Storage::as_type_description();
+
+    for storage in [
+        Storage::InMemory,
+        Storage::OnDisk {
+            path: "/var/lib".to_string(),
+        },
+    ] {
+        let value = serde_json::to_value(storage).unwrap();
+        assert_eq!(desc.validate_value(&value), Ok(()));
+    }
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+enum ExternalShape {
+    Point,
+    Circle { radius: f32 },
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+#[description(use_serde)]
+enum InternalShape {
+    Point,
+    Circle { radius: f32 },
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(untagged)]
+#[description(use_serde)]
+enum UntaggedShape {
+    Radius(f32),
+    Circle { radius: f32 },
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(untagged)]
+#[description(use_serde)]
+#[description(tag = "kind")]
+enum OverriddenShape {
+    Point,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+#[description(use_serde)]
+enum AdjacentShape {
+    Point,
+    Circle { radius: f32 },
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(tag = "kind", content = "data")]
+enum ExplicitAdjacentShape {
+    Point,
+}
+
+#[test]
+fn check_serde_tagging() {
+    use type_description::{TypeEnumKind, TypeKind};
+
+    let tagging = |desc: type_description::TypeDescription| match desc.kind() {
+        TypeKind::Enum { tagging: kind, .. } => kind.clone(),
+        _ => panic!("Should be an enum"),
+    };
+
+    assert_eq!(
+        tagging(ExternalShape::as_type_description()),
+        TypeEnumKind::External
+    );
+    assert_eq!(
+        tagging(InternalShape::as_type_description()),
+        TypeEnumKind::Tagged("kind".into())
+    );
+    assert_eq!(
+        tagging(UntaggedShape::as_type_description()),
+        TypeEnumKind::Untagged
+    );
+    assert_eq!(
+        tagging(OverriddenShape::as_type_description()),
+        TypeEnumKind::Tagged("kind".into())
+    );
+    assert_eq!(
+        tagging(AdjacentShape::as_type_description()),
+        TypeEnumKind::Adjacent {
+            tag: "t".into(),
+            content: "c".into(),
+        }
+    );
+    assert_eq!(
+        tagging(ExplicitAdjacentShape::as_type_description()),
+        TypeEnumKind::Adjacent {
+            tag: "kind".into(),
+            content: "data".into(),
+        }
+    );
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct Flattened {
+    name: String,
+    #[serde(flatten)]
+    listener: Listener,
+    #[serde(flatten)]
+    extra: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct OptionallyFlattened {
+    name: String,
+    #[serde(flatten)]
+    listener: Option<Listener>,
+    #[serde(flatten, default)]
+    limits: Limits,
+}
+
+#[derive(Debug, Default, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct Limits {
+    max_connections: u32,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct Nested {
+    name: String,
+    #[serde(rename = "bind-address")]
+    bind_address: String,
+    port: u16,
+}
+
+#[test]
+fn check_flatten_matches_nested() {
+    let flattened = match Flattened::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => fields.clone(),
+        _ => panic!("Should be a struct"),
+    };
+    let nested = match Nested::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => fields.clone(),
+        _ => panic!("Should be a struct"),
+    };
+
+    assert_eq!(flattened[..3], nested[..]);
+
+    // A map has no fields to inline, so it stays a field of its own
+    assert_eq!(flattened.len(), 4);
+    assert_eq!(flattened[3].name(), "extra");
+    assert!(flattened[3].optional());
+    assert_eq!(
+        flattened[3].kind(),
+        &std::collections::HashMap::<String, String>::as_type_description()
+    );
+}
+
+#[test]
+fn check_optional_flatten() {
+    let fields = match OptionallyFlattened::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => fields.clone(),
+        _ => panic!("Should be a struct"),
+    };
+
+    let optional = fields
+        .iter()
+        .map(|field| (field.name(), field.optional()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        optional,
+        [
+            ("name", false),
+            ("bind-address", true),
+            ("port", true),
+            ("max_connections", true),
+        ]
+    );
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct Renamed {
+    #[serde(rename = "bind-address")]
+    #[description(rename = "Bind address")]
+    bind_address: String,
+    #[serde(rename = "port")]
+    listen_port: u16,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(rename_all = "lowercase")]
+enum Verbosity {
+    #[description(rename = "Quiet mode")]
+    Quiet,
+    #[serde(rename = "loud")]
+    #[description(rename = "Loud mode")]
+    Verbose,
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(tag = "type")]
+enum RenamedStorage {
+    #[description(rename = "Disk storage")]
+    Disk { path: String },
+}
+
+#[test]
+fn check_description_rename_wins() {
+    match Renamed::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].name(), "bind-address");
+            assert_eq!(fields[0].label(), "Bind address");
+            assert_eq!(fields[1].name(), "port");
+            assert_eq!(fields[1].label(), "port");
+        }
+        _ => panic!("Should be a struct"),
+    }
+
+    match Verbosity::as_type_description().kind() {
+        type_description::TypeKind::Enum { variants, .. } => {
+            assert_eq!(variants[0].label(), "Quiet mode");
+            assert_eq!(
+                variants[0].repr(),
+                &type_description::EnumVariantRepresentation::String("quiet".into())
+            );
+            assert_eq!(variants[1].label(), "Loud mode");
+            assert_eq!(
+                variants[1].repr(),
+                &type_description::EnumVariantRepresentation::String("loud".into())
+            );
+        }
+        _ => panic!("Should be an enum"),
+    }
+
+    match RenamedStorage::as_type_description().kind() {
+        type_description::TypeKind::Enum { variants, .. } => {
+            assert_eq!(variants[0].name(), "Disk");
+            assert_eq!(variants[0].label(), "Disk storage");
+        }
+        _ => panic!("Should be an enum"),
+    }
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn check_description_rename_validates() {
+    let value = serde_json::to_value(Renamed {
+        bind_address: "x".to_string(),
+        listen_port: 80,
+    })
+    .unwrap();
+    assert_eq!(
+        Renamed::as_type_description().validate_value(&value),
+        Ok(())
+    );
+
+    let value = serde_json::to_value(Verbosity::Verbose).unwrap();
+    assert_eq!(
+        Verbosity::as_type_description().validate_value(&value),
+        Ok(())
+    );
+
+    let value = serde_json::to_value(RenamedStorage::Disk {
+        path: "/tmp".to_string(),
+    })
+    .unwrap();
+    assert_eq!(
+        RenamedStorage::as_type_description().validate_value(&value),
+        Ok(())
+    );
+}
+
+fn default_port() -> u16 {
+    8080
+}
+
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+struct Defaults {
+    host: String,
+    #[serde(default)]
+    verbose: bool,
+    #[serde(default = "default_port")]
+    port: u16,
+}
+
+#[derive(Debug, Default, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(default)]
+struct AllDefaults {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn check_serde_default_is_optional() {
+    match Defaults::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert!(!fields[0].optional());
+            assert!(fields[1].optional());
+            assert!(fields[2].optional());
+            assert_eq!(fields[2].kind(), &u16::as_type_description());
+        }
+        _ => panic!("Should be a struct"),
+    }
+
+    match AllDefaults::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert!(fields.iter().all(|field| field.optional()));
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+/// A port to listen on
+#[derive(Debug, TypeDescription, Serialize, Deserialize)]
+#[description(use_serde)]
+#[serde(transparent)]
+struct Port {
+    number: u16,
+    #[serde(skip)]
+    _cache: Option<String>,
+}
+
+#[test]
+fn check_transparent_is_inner_type() {
+    let desc = Port::as_type_description();
+
+    assert_eq!(desc.name(), "Port");
+    assert_eq!(desc.doc(), Some("A port to listen on"));
+    assert_eq!(
+        desc.kind(),
+        &type_description::TypeKind::Wrapped(Box::new(u16::as_type_description()))
+    );
+}
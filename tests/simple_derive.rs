@@ -42,3 +42,409 @@ fn check_type_description() {
 
     println!("{:#?}", desc);
 }
+
+#[derive(Debug, TypeDescription)]
+struct Cache {
+    size: u64,
+    #[description(skip)]
+    entries: std::cell::Cell<u64>,
+}
+
+#[derive(Debug, TypeDescription)]
+struct Internal {
+    #[description(skip)]
+    entries: std::cell::Cell<u64>,
+}
+
+#[test]
+fn check_skipped_fields() {
+    match Cache::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(fields.len(), 1);
+            assert_eq!(fields[0].name(), "size");
+        }
+        _ => panic!("Should be a struct"),
+    }
+
+    assert_eq!(
+        Internal::as_type_description().kind(),
+        &type_description::TypeKind::Struct(vec![])
+    );
+}
+
+mod opaque {
+    #[derive(Debug)]
+    pub struct Token(pub String);
+
+    #[derive(Debug)]
+    pub struct Counter(pub u64);
+}
+
+#[derive(Debug, TypeDescription)]
+struct Credentials {
+    #[description(kind = "String")]
+    token: opaque::Token,
+    /// How often the token was used
+    #[description(kind = "Integer")]
+    uses: opaque::Counter,
+}
+
+#[test]
+fn check_kind_override() {
+    match Credentials::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].kind(), &String::as_type_description());
+            assert_eq!(fields[1].doc(), Some("How often the token was used"));
+            assert!(matches!(
+                fields[1].kind().kind(),
+                type_description::TypeKind::Integer { .. }
+            ));
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Certificate {
+    #[description(kind = "Bytes")]
+    der: Vec<u8>,
+}
+
+#[test]
+fn check_bytes_kind_override() {
+    match Certificate::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(
+                fields[0].kind(),
+                &type_description::Bytes::as_type_description()
+            );
+            assert!(matches!(
+                fields[0].kind().kind(),
+                type_description::TypeKind::Bytes
+            ));
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Passthrough {
+    /// Handed to the plugin without being looked at
+    #[description(kind = "Any")]
+    extra: std::collections::HashMap<String, String>,
+}
+
+#[test]
+fn check_any_kind_override() {
+    match Passthrough::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].kind().kind(), &type_description::TypeKind::Any);
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(untagged)]
+enum Mode {
+    /// Run in the foreground
+    Foreground,
+    /// Run in the background
+    ///
+    /// Logs go to syslog
+    Daemon,
+}
+
+#[test]
+fn check_variant_docs() {
+    match Mode::as_type_description().kind() {
+        type_description::TypeKind::Enum { variants, .. } => {
+            assert_eq!(variants[0].doc(), Some("Run in the foreground"));
+            assert_eq!(
+                variants[1].doc(),
+                Some("Run in the background\n\nLogs go to syslog")
+            );
+        }
+        _ => panic!("Should be an enum"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(tag = "event")]
+enum Event {
+    Started,
+    #[description(variant_repr = "wrapped")]
+    Stopped,
+    #[description(variant_repr = "string")]
+    Paused,
+}
+
+#[test]
+fn check_variant_repr_override() {
+    use type_description::{EnumVariantRepresentation, TypeDescription, TypeKind};
+
+    match Event::as_type_description().kind() {
+        TypeKind::Enum { variants, .. } => {
+            assert_eq!(
+                variants[0].repr(),
+                &EnumVariantRepresentation::String("Started".into())
+            );
+            assert_eq!(
+                variants[1].repr(),
+                &EnumVariantRepresentation::Wrapped(Box::new(TypeDescription::new(
+                    "Stopped".into(),
+                    TypeKind::Struct(vec![]),
+                    None
+                )))
+            );
+            assert_eq!(
+                variants[2].repr(),
+                &EnumVariantRepresentation::String("Paused".into())
+            );
+        }
+        _ => panic!("Should be an enum"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Endpoint {
+    #[description(min = 1, max = 65535)]
+    port: u32,
+    #[description(min_len = 1, max_len = 253, pattern = "^[a-z0-9.-]+$")]
+    host: String,
+}
+
+#[test]
+fn check_constraints() {
+    use type_description::Constraint;
+
+    match Endpoint::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(
+                fields[0].kind().constraints(),
+                [Constraint::Min(1), Constraint::Max(65535)]
+            );
+            assert_eq!(
+                fields[1].kind().constraints(),
+                [
+                    Constraint::MinLen(1),
+                    Constraint::MaxLen(253),
+                    Constraint::Pattern("^[a-z0-9.-]+$".into())
+                ]
+            );
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Listen {
+    #[description(example = "8080")]
+    #[description(example = "443", min = 1)]
+    port: u16,
+    host: String,
+}
+
+#[test]
+fn check_examples() {
+    match Listen::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].kind().examples(), ["8080", "443"]);
+            assert_eq!(
+                fields[0].kind().constraints(),
+                [type_description::Constraint::Min(1)]
+            );
+            assert!(fields[1].kind().examples().is_empty());
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Inventory {
+    doors: std::collections::HashMap<Door, u32>,
+}
+
+#[test]
+fn check_enum_map_keys() {
+    let desc = Inventory::as_type_description();
+
+    match desc.kind() {
+        type_description::TypeKind::Struct(fields) => match fields[0].kind().kind() {
+            type_description::TypeKind::HashMap { key, value } => {
+                assert_eq!(**key, Door::as_type_description());
+                assert_eq!(**value, u32::as_type_description());
+            }
+            other => panic!("Expected HashMap, got {:?}", other),
+        },
+        other => panic!("Expected Struct, got {:?}", other),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Proxy {
+    host: String,
+    /// Defaults to 3128
+    port: Option<u16>,
+    auth: std::option::Option<Vec<String>>,
+}
+
+#[test]
+fn check_option_fields_are_optional() {
+    match Proxy::as_type_description().kind() {
+        type_description::TypeKind::Struct(fields) => {
+            assert!(!fields[0].optional());
+
+            assert!(fields[1].optional());
+            assert_eq!(fields[1].doc(), Some("Defaults to 3128"));
+            assert_eq!(fields[1].kind(), &u16::as_type_description());
+
+            assert!(fields[2].optional());
+            assert_eq!(fields[2].kind(), &Vec::<String>::as_type_description());
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+/// The port to listen on
+#[derive(Debug, TypeDescription)]
+struct Port(u16);
+
+#[test]
+fn check_newtype() {
+    let desc = Port::as_type_description();
+
+    assert_eq!(desc.name(), "Port");
+    assert_eq!(desc.doc(), Some("The port to listen on"));
+    assert_eq!(
+        desc.kind(),
+        &type_description::TypeKind::Wrapped(Box::new(u16::as_type_description()))
+    );
+}
+
+/// A point on the screen
+#[derive(Debug, TypeDescription)]
+struct Point(u32, u32);
+
+#[test]
+fn check_tuple_struct() {
+    let desc = Point::as_type_description();
+
+    assert_eq!(desc.name(), "Point");
+    assert_eq!(desc.doc(), Some("A point on the screen"));
+    assert_eq!(
+        desc.kind(),
+        &type_description::TypeKind::Tuple(vec![
+            u32::as_type_description(),
+            u32::as_type_description()
+        ])
+    );
+}
+
+/// Enables the experimental features
+#[derive(Debug, TypeDescription)]
+struct Experimental;
+
+#[test]
+fn check_unit_struct() {
+    let desc = Experimental::as_type_description();
+
+    assert_eq!(desc.name(), "Experimental");
+    assert_eq!(desc.doc(), Some("Enables the experimental features"));
+    assert_eq!(desc.kind(), &type_description::TypeKind::Unit);
+}
+
+/// The server config
+///
+/// Read from `server.toml` on startup.
+#[derive(Debug, TypeDescription)]
+struct ServerConfig {
+    port: u16,
+}
+
+/// How the server logs
+#[derive(Debug, TypeDescription)]
+#[description(untagged)]
+enum LogTarget {
+    Stdout,
+    Syslog,
+}
+
+#[test]
+fn check_container_docs() {
+    assert_eq!(
+        ServerConfig::as_type_description().doc(),
+        Some("The server config\n\nRead from `server.toml` on startup.")
+    );
+    assert_eq!(
+        LogTarget::as_type_description().doc(),
+        Some("How the server logs")
+    );
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(untagged)]
+#[non_exhaustive]
+enum Compression {
+    Gzip,
+    Zstd,
+}
+
+#[test]
+fn check_non_exhaustive() {
+    assert!(matches!(
+        Compression::as_type_description().kind(),
+        type_description::TypeKind::Enum {
+            non_exhaustive: true,
+            ..
+        }
+    ));
+    assert!(matches!(
+        Door::as_type_description().kind(),
+        type_description::TypeKind::Enum {
+            non_exhaustive: false,
+            ..
+        }
+    ));
+}
+
+#[cfg(feature = "render_signature")]
+#[test]
+fn check_signature() {
+    use type_description::render::render_signature;
+
+    #[derive(TypeDescription)]
+    #[description(tag = "kind")]
+    enum Storage {
+        Memory,
+        Disk(Window),
+    }
+
+    assert_eq!(
+        render_signature(&Storage::as_type_description()),
+        "Memory | Disk(Window { open: Boolean })"
+    );
+}
+
+/// Where the server listens
+#[derive(Debug, TypeDescription)]
+#[description(name = "Server Configuration")]
+struct ServerConf {
+    port: u16,
+}
+
+#[derive(Debug, TypeDescription)]
+#[description(name = "Log level", untagged)]
+enum Level {
+    Info,
+    Debug,
+}
+
+#[test]
+fn check_custom_name() {
+    let desc = ServerConf::as_type_description();
+    assert_eq!(desc.name(), "Server Configuration");
+    assert_eq!(desc.doc(), Some("Where the server listens"));
+
+    assert_eq!(Level::as_type_description().name(), "Log level");
+}
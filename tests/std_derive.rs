@@ -0,0 +1,105 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use type_description::{AsTypeDescription, TypeDescription, TypeKind};
+
+#[derive(Debug, TypeDescription)]
+struct Paths {
+    /// Where the data lives
+    data_dir: PathBuf,
+    log_file: Option<PathBuf>,
+}
+
+#[test]
+fn check_path_fields() {
+    let desc = Paths::as_type_description();
+
+    match desc.kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].name(), "data_dir");
+            assert_eq!(fields[0].kind().kind(), &TypeKind::String);
+            assert_eq!(fields[0].kind().doc(), Some("A filesystem path"));
+        }
+        _ => panic!("Should be a struct"),
+    }
+
+    assert_eq!(
+        <&std::path::Path>::as_type_description(),
+        PathBuf::as_type_description()
+    );
+}
+
+#[derive(Debug, TypeDescription)]
+struct Network {
+    address: std::net::IpAddr,
+    gateway: std::net::Ipv4Addr,
+    dns: Vec<std::net::Ipv6Addr>,
+}
+
+#[test]
+fn check_ip_fields() {
+    match Network::as_type_description().kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].kind().kind(), &TypeKind::String);
+            assert_eq!(fields[0].kind().doc(), Some("An IP address"));
+            assert_eq!(fields[1].kind().doc(), Some("An IPv4 address"));
+            assert!(
+                matches!(fields[2].kind().kind(), TypeKind::Array(inner) if inner.doc() == Some("An IPv6 address"))
+            );
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[derive(Debug, TypeDescription)]
+struct Process {
+    program: std::ffi::OsString,
+    args: Vec<std::ffi::OsString>,
+    name: std::ffi::CString,
+}
+
+#[test]
+fn check_ffi_string_fields() {
+    match Process::as_type_description().kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[0].kind().kind(), &TypeKind::String);
+            assert_eq!(
+                fields[0].kind().doc(),
+                Some("A platform string, which does not have to be valid UTF-8 on every platform")
+            );
+            assert!(
+                matches!(fields[1].kind().kind(), TypeKind::Array(inner) if inner.kind() == &TypeKind::String)
+            );
+            assert_eq!(fields[2].kind().kind(), &TypeKind::String);
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(Debug, TypeDescription)]
+struct Plugin {
+    name: String,
+    /// Passed on to the plugin as is
+    settings: serde_json::Value,
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn check_json_value_field() {
+    match Plugin::as_type_description().kind() {
+        TypeKind::Struct(fields) => {
+            assert_eq!(fields[1].name(), "settings");
+            assert_eq!(fields[1].kind().kind(), &TypeKind::Any);
+            assert_eq!(fields[1].kind().doc(), Some("Any JSON value"));
+        }
+        _ => panic!("Should be a struct"),
+    }
+}
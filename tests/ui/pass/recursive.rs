@@ -0,0 +1,44 @@
+//
+//   This Source Code Form is subject to the terms of the Mozilla Public
+//   License, v. 2.0. If a copy of the MPL was not distributed with this
+//   file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#![allow(dead_code)]
+
+use type_description::{AsTypeDescription, TypeDescription, TypeKind};
+
+#[derive(TypeDescription)]
+struct Tree {
+    value: u32,
+    children: Vec<Tree>,
+}
+
+#[derive(TypeDescription)]
+struct Directory {
+    files: Vec<String>,
+    listing: Option<Box<Listing>>,
+}
+
+#[derive(TypeDescription)]
+#[description(untagged)]
+enum Listing {
+    Directory(Directory),
+    File(String),
+}
+
+fn main() {
+    let tree = Tree::as_type_description();
+    match tree.kind() {
+        TypeKind::Struct(fields) => match fields[1].kind().kind() {
+            TypeKind::Array(inner) => assert_eq!(inner.kind(), &TypeKind::Ref("Tree".into())),
+            other => panic!("Expected Array, got {:?}", other),
+        },
+        other => panic!("Expected Struct, got {:?}", other),
+    }
+
+    let directory = Directory::as_type_description();
+    assert!(directory.contains_kind(|kind| kind == &TypeKind::Ref("Directory".into())));
+    assert!(!directory.contains_kind(|kind| kind == &TypeKind::Ref("Listing".into())));
+    assert!(directory.depth() < 10);
+}
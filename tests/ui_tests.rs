@@ -11,4 +11,13 @@ fn check_type_description() {
     let tests = trybuild::TestCases::new();
 
     tests.compile_fail("tests/ui/fail/*.rs");
+    tests.pass("tests/ui/pass/*.rs");
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn check_pattern_validation() {
+    let tests = trybuild::TestCases::new();
+
+    tests.compile_fail("tests/ui/regex/*.rs");
 }
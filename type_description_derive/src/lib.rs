@@ -6,23 +6,36 @@
 
 use proc_macro::TokenStream as TS;
 use proc_macro2::TokenStream;
-use proc_macro_error::{abort, proc_macro_error, OptionExt, ResultExt};
-use quote::{quote, ToTokens, TokenStreamExt};
+use proc_macro_error::{abort, proc_macro_error, ResultExt};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{
-    parse_macro_input, Attribute, DeriveInput, Ident, Lit, LitStr, Meta, MetaNameValue, NestedMeta,
-    Type,
+    parse_macro_input, spanned::Spanned, Attribute, DeriveInput, Ident, Lit, LitStr, Meta,
+    MetaNameValue, NestedMeta, Type,
 };
 
 #[derive(Debug)]
 enum TypeField<'q> {
     Simple {
-        ident: Ident,
+        name: String,
+        /// The name to document the field with, if it differs from the serialized name
+        label: Option<String>,
         ty: &'q Type,
+        /// A built-in type to describe the field with instead of `ty`
+        kind: Option<Type>,
+        constraints: Vec<TokenStream>,
+        /// Example values, in the order they are given
+        examples: Vec<LitStr>,
         docs: Option<Vec<LitStr>>,
         optional: bool,
     },
+    /// A field whose fields are inlined into the containing struct
     Flatten {
+        name: String,
+        label: Option<String>,
         ty: &'q Type,
+        docs: Option<Vec<LitStr>>,
+        /// Whether the inlined fields can all be left out, e.g. for an `Option`
+        optional: bool,
     },
 }
 
@@ -35,6 +48,9 @@ enum TypeVariantKind<'q> {
 
 #[derive(Debug)]
 struct TypeVariant<'q> {
+    name: String,
+    /// The name to document the variant with, if it differs from the serialized name
+    label: Option<String>,
     kind: TypeVariantKind<'q>,
     docs: Option<Vec<LitStr>>,
 }
@@ -42,23 +58,35 @@ struct TypeVariant<'q> {
 #[derive(Debug)]
 enum TypeEnumKind {
     Tagged(LitStr),
+    Adjacent { tag: LitStr, content: LitStr },
     Untagged,
+    External,
 }
 
 #[derive(Debug)]
 enum TypeQuoteKind<'q> {
     Wrapped(&'q Type),
+    Tuple(Vec<&'q Type>),
+    Unit,
     Struct(Vec<TypeField<'q>>),
-    Enum(TypeEnumKind, Vec<TypeVariant<'q>>),
+    Enum(TypeEnumKind, Vec<TypeVariant<'q>>, bool),
 }
 
 #[derive(Debug)]
 struct TypeQuote<'q> {
-    ident: &'q Ident,
+    name: String,
     docs: Option<Vec<LitStr>>,
     kind: TypeQuoteKind<'q>,
 }
 
+/// Describe the given type, reporting a missing `AsTypeDescription` implementation at the type
+/// itself instead of at the derive
+fn quote_describe(ty: &Type) -> TokenStream {
+    quote_spanned! {ty.span()=>
+        <#ty as ::type_description::AsTypeDescription>::as_type_description()
+    }
+}
+
 fn lit_strings_to_string_quoted(docs: &Option<Vec<LitStr>>) -> TokenStream {
     if let Some(docs) = docs {
         let docs = docs
@@ -96,16 +124,21 @@ fn extract_docs_from_attributes<'a>(
 }
 
 #[derive(Debug, PartialEq)]
-enum SerdeFieldAttribute {
+enum SerdeAttribute {
     Rename(LitStr),
+    RenameAll(LitStr),
+    Tag(LitStr),
+    Content(LitStr),
+    Untagged,
+    Transparent,
     HasDefault,
     Flatten,
     Skip,
 }
 
-fn extra_serde_field_attributes<'a>(
+fn extract_serde_attributes<'a>(
     attrs: impl Iterator<Item = &'a Attribute>,
-) -> Option<Vec<SerdeFieldAttribute>> {
+) -> Option<Vec<SerdeAttribute>> {
     let attrs = attrs
         .filter(|attr| attr.path.is_ident("serde"))
         .flat_map(|attr| match attr.parse_meta() {
@@ -119,19 +152,39 @@ fn extra_serde_field_attributes<'a>(
                             Meta::NameValue(meta) => {
                                 if meta.path.is_ident("rename") {
                                     if let Lit::Str(litstr) = meta.lit {
-                                        return Some(SerdeFieldAttribute::Rename(litstr));
+                                        return Some(SerdeAttribute::Rename(litstr));
+                                    }
+                                } else if meta.path.is_ident("rename_all") {
+                                    if let Lit::Str(litstr) = meta.lit {
+                                        return Some(SerdeAttribute::RenameAll(litstr));
+                                    }
+                                } else if meta.path.is_ident("tag") {
+                                    if let Lit::Str(litstr) = meta.lit {
+                                        return Some(SerdeAttribute::Tag(litstr));
+                                    }
+                                } else if meta.path.is_ident("content") {
+                                    if let Lit::Str(litstr) = meta.lit {
+                                        return Some(SerdeAttribute::Content(litstr));
                                     }
+                                } else if meta.path.is_ident("default") {
+                                    return Some(SerdeAttribute::HasDefault);
                                 }
                             }
                             Meta::Path(path) => {
                                 if path.is_ident("default") {
-                                    return Some(SerdeFieldAttribute::HasDefault);
+                                    return Some(SerdeAttribute::HasDefault);
                                 }
                                 if path.is_ident("flatten") {
-                                    return Some(SerdeFieldAttribute::Flatten);
+                                    return Some(SerdeAttribute::Flatten);
+                                }
+                                if path.is_ident("untagged") {
+                                    return Some(SerdeAttribute::Untagged);
+                                }
+                                if path.is_ident("transparent") {
+                                    return Some(SerdeAttribute::Transparent);
                                 }
                                 if path.is_ident("skip") || path.is_ident("skip_deserializing") {
-                                    return Some(SerdeFieldAttribute::Skip);
+                                    return Some(SerdeAttribute::Skip);
                                 }
                             }
                             _ => {}
@@ -151,161 +204,459 @@ fn extra_serde_field_attributes<'a>(
     }
 }
 
+#[derive(Debug)]
+enum DescriptionFieldAttribute {
+    Skip,
+    /// The name to document the field or variant with
+    ///
+    /// This is kept as a label next to the serialized name, which still follows
+    /// `#[serde(rename)]`, `#[serde(rename_all)]` and the identifier itself.
+    Rename(LitStr),
+    Kind(LitStr),
+    Constraint(TokenStream),
+    Example(LitStr),
+    /// Whether an enum variant is described as a bare string or as a wrapped value, regardless
+    /// of whether it has fields
+    VariantRepr(VariantRepr),
+}
+
+#[derive(Debug)]
+enum VariantRepr {
+    String,
+    Wrapped,
+}
+
+/// Map the name of a built-in kind to the type whose description it stands for
+fn builtin_kind_to_type(kind: &LitStr) -> Type {
+    match kind.value().as_str() {
+        "Bool" => syn::parse_quote!(bool),
+        "Integer" => syn::parse_quote!(i64),
+        "Float" => syn::parse_quote!(f64),
+        "String" => syn::parse_quote!(::std::string::String),
+        "Char" => syn::parse_quote!(char),
+        "Bytes" => syn::parse_quote!(::type_description::Bytes),
+        "Any" => syn::parse_quote!(::type_description::AnyValue),
+        _ => abort!(
+            kind,
+            "Unknown built-in kind";
+            help = "Use one of \"Bool\", \"Integer\", \"Float\", \"String\", \"Char\", \"Bytes\" or \"Any\""
+        ),
+    }
+}
+
+fn extract_description_field_attributes<'a>(
+    attrs: impl Iterator<Item = &'a Attribute>,
+) -> Vec<DescriptionFieldAttribute> {
+    attrs
+        .filter(|attr| attr.path.is_ident("description"))
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => list
+                .nested
+                .into_iter()
+                .map(|meta| match meta {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                        DescriptionFieldAttribute::Skip
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("kind") => DescriptionFieldAttribute::Kind(lit_str),
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("rename") => DescriptionFieldAttribute::Rename(lit_str),
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("example") => DescriptionFieldAttribute::Example(lit_str),
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("variant_repr") => match lit_str.value().as_str() {
+                        "string" => DescriptionFieldAttribute::VariantRepr(VariantRepr::String),
+                        "wrapped" => DescriptionFieldAttribute::VariantRepr(VariantRepr::Wrapped),
+                        _ => abort!(
+                            lit_str,
+                            "Unknown variant representation";
+                            help = "Use either \"string\" or \"wrapped\""
+                        ),
+                    },
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) if path.is_ident("pattern") => {
+                        #[cfg(feature = "regex")]
+                        if let Err(err) = regex_syntax::Parser::new().parse(&lit_str.value()) {
+                            abort!(lit_str, "Invalid regular expression: {}", err)
+                        }
+                        DescriptionFieldAttribute::Constraint(
+                            quote!(::type_description::Constraint::Pattern(::std::borrow::Cow::Borrowed(#lit_str))),
+                        )
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Int(lit_int),
+                        ..
+                    })) => {
+                        let constraint = if path.is_ident("min") || path.is_ident("max") {
+                            let value = lit_int.base10_parse::<i64>().unwrap_or_abort();
+                            if path.is_ident("min") {
+                                quote!(Min(#value))
+                            } else {
+                                quote!(Max(#value))
+                            }
+                        } else if path.is_ident("min_len") || path.is_ident("max_len") {
+                            let value = lit_int.base10_parse::<usize>().unwrap_or_abort();
+                            if path.is_ident("min_len") {
+                                quote!(MinLen(#value))
+                            } else {
+                                quote!(MaxLen(#value))
+                            }
+                        } else {
+                            abort!(path, "Unknown #[description] field attribute")
+                        };
+                        DescriptionFieldAttribute::Constraint(
+                            quote!(::type_description::Constraint::#constraint),
+                        )
+                    }
+                    other => abort!(other, "Unknown #[description] field attribute"),
+                })
+                .collect::<Vec<_>>(),
+            _ => abort!(attr, "Expected a list, like #[description(skip)]"),
+        })
+        .collect()
+}
+
+/// The case conversions supported by `#[serde(rename_all = "...")]`
+#[derive(Debug, Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit: &LitStr) -> RenameRule {
+        match lit.value().as_str() {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+            _ => abort!(
+                lit,
+                "Unknown rename rule";
+                help = "Use one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\" or \"SCREAMING-KEBAB-CASE\""
+            ),
+        }
+    }
+
+    /// Apply the rule to a `snake_case` field name
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to a `PascalCase` variant name
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::Pascal => variant.to_string(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => variant.to_string(),
+                }
+            }
+            RenameRule::Snake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake
+                .apply_to_variant(variant)
+                .to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+}
+
+fn extract_serde_rename_all<'a>(attrs: impl Iterator<Item = &'a Attribute>) -> Option<RenameRule> {
+    extract_serde_attributes(attrs)
+        .unwrap_or_default()
+        .iter()
+        .find_map(|attr| match attr {
+            SerdeAttribute::RenameAll(lit) => Some(RenameRule::from_lit(lit)),
+            _ => None,
+        })
+}
+
+fn quote_label(label: &Option<String>) -> TokenStream {
+    match label {
+        Some(label) => quote!(.with_label(#label)),
+        None => quote!(),
+    }
+}
+
+fn quote_struct_fields(fields: &[TypeField<'_>]) -> TokenStream {
+    let fields = fields.iter().map(|field| match field {
+        TypeField::Simple {
+            name,
+            label,
+            ty,
+            kind,
+            constraints,
+            examples,
+            docs,
+            optional,
+        } => {
+            let docs = lit_strings_to_string_quoted(docs);
+            let desc = quote_describe(kind.as_ref().unwrap_or(ty));
+            let constraints = if constraints.is_empty() {
+                quote!()
+            } else {
+                quote!(.with_constraints(vec![#(#constraints),*]))
+            };
+            let examples = if examples.is_empty() {
+                quote!()
+            } else {
+                quote!(.with_examples(vec![#(::std::borrow::Cow::Borrowed(#examples)),*]))
+            };
+            let label = quote_label(label);
+            quote! {
+                [::type_description::StructField::new(#name, #docs, #desc #constraints #examples, #optional) #label]
+            }
+        }
+        TypeField::Flatten {
+            name,
+            label,
+            ty,
+            docs,
+            optional,
+        } => {
+            let docs = lit_strings_to_string_quoted(docs);
+            let label = quote_label(label);
+            let desc = quote_describe(ty);
+            // Anything that has no fields to inline, like a map collecting the remaining keys, is
+            // kept as an optional field of its own
+            quote! {
+                {
+                    let mut desc = #desc;
+                    while let ::type_description::TypeKind::Wrapped(inner) = desc.kind() {
+                        desc = (**inner).clone();
+                    }
+                    match desc.kind() {
+                        ::type_description::TypeKind::Struct(fields) => fields
+                            .iter()
+                            .map(|field| {
+                                let optional = #optional || field.optional();
+                                field.clone().with_optional(optional)
+                            })
+                            .collect(),
+                        _ => vec![::type_description::StructField::new(#name, #docs, desc, true) #label],
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        {
+            let mut fields = vec![];
+            #(
+                fields.extend(#fields);
+            )*
+            fields
+        }
+    }
+}
+
 impl<'q> ToTokens for TypeQuote<'q> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let ident_name = self.ident.to_string();
+        let ident_name = &self.name;
         let outer_docs = lit_strings_to_string_quoted(&self.docs);
 
         tokens.append_all(match &self.kind {
             TypeQuoteKind::Wrapped(ty) => {
+                let desc = quote_describe(ty);
                 quote! {
                     ::type_description::TypeDescription::new(
                         ::std::string::String::from(#ident_name),
                         ::type_description::TypeKind::Wrapped(
-                            ::std::boxed::Box::new(<#ty as ::type_description::AsTypeDescription>::as_type_description())
+                            ::std::boxed::Box::new(#desc)
                         ),
                         #outer_docs
                     )
                 }
             }
+            TypeQuoteKind::Unit => {
+                quote! {
+                    ::type_description::TypeDescription::new(
+                        ::std::string::String::from(#ident_name),
+                        ::type_description::TypeKind::Unit,
+                        #outer_docs
+                    )
+                }
+            }
+            TypeQuoteKind::Tuple(elements) => {
+                let elements = elements.iter().map(|ty| quote_describe(ty));
+                quote! {
+                    ::type_description::TypeDescription::new(
+                        ::std::string::String::from(#ident_name),
+                        ::type_description::TypeKind::Tuple(::std::vec![
+                            #(#elements),*
+                        ]),
+                        #outer_docs
+                    )
+                }
+            }
             TypeQuoteKind::Struct(fields) => {
-
-                let fields = fields.iter().map(|field| {
-
-                    match field {
-                        TypeField::Simple { ident, ty, docs, optional } =>  {
-                            let ident = ident.to_string();
-                            let docs = lit_strings_to_string_quoted(docs);
-                            quote! {
-                                [::type_description::StructField::new(#ident, #docs, <#ty as ::type_description::AsTypeDescription>::as_type_description(), #optional)]
-                            }
-                        }
-                        TypeField::Flatten { ty } => {
-                            quote! {
-                                {
-                                    let desc = <#ty as ::type_description::AsTypeDescription>::as_type_description();
-                                    match desc.kind() {
-                                        ::type_description::TypeKind::Struct(fields) => fields.clone(),
-                                        _ => panic!("Tried to flatten a non-struct field")
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                });
+                let fields = quote_struct_fields(fields);
 
                 quote! {
                     ::type_description::TypeDescription::new(
                         ::std::string::String::from(#ident_name),
-                        ::type_description::TypeKind::Struct({
-                            let mut fields = vec![];
-                            #(
-                                fields.extend(#fields);
-                            )*
-                            fields
-                        }),
+                        ::type_description::TypeKind::Struct(#fields),
                         #outer_docs
                     )
                 }
             }
-            TypeQuoteKind::Enum(kind, variants) => {
+            TypeQuoteKind::Enum(kind, variants, non_exhaustive) => {
                 let kind = match kind {
                     TypeEnumKind::Tagged(tag) => {
                         quote! {
                             ::type_description::TypeEnumKind::Tagged(::std::borrow::Cow::Borrowed(#tag))
                         }
                     }
+                    TypeEnumKind::Adjacent { tag, content } => {
+                        quote! {
+                            ::type_description::TypeEnumKind::Adjacent {
+                                tag: ::std::borrow::Cow::Borrowed(#tag),
+                                content: ::std::borrow::Cow::Borrowed(#content),
+                            }
+                        }
+                    }
                     TypeEnumKind::Untagged => {
                         quote! {
                             ::type_description::TypeEnumKind::Untagged
                         }
                     }
+                    TypeEnumKind::External => {
+                        quote! {
+                            ::type_description::TypeEnumKind::External
+                        }
+                    }
                 };
 
                 let variants = variants.iter().map(|var| {
                     let docs = lit_strings_to_string_quoted(&var.docs);
+                    let name = &var.name;
+                    let label = quote_label(&var.label);
                     match &var.kind {
-                        TypeVariantKind::Wrapped(ident, TypeField::Flatten { ty: _ }) => {
+                        TypeVariantKind::Wrapped(ident, TypeField::Flatten { .. }) => {
                             abort!(
                                 ident,
                                 "Cannot flatten wrapped fields"
                             )
                         }
-                        TypeVariantKind::Wrapped(ident, TypeField::Simple { ty, .. }) => {
+                        TypeVariantKind::Wrapped(_, TypeField::Simple { ty, .. }) => {
                             // we ignore the above docs since the outer docs are the important ones
                             // TODO: Emit an error if an inner type in a enum is annotated
-                            let ident = ident.to_string();
+                            let desc = quote_describe(ty);
                             quote!{
                                 ::type_description::EnumVariant::new(
-                                    #ident,
+                                    #name,
                                     #docs,
                                     ::type_description::EnumVariantRepresentation::Wrapped(
                                         std::boxed::Box::new(::type_description::TypeDescription::new(
-                                            ::std::string::String::from(#ident),
+                                            ::std::string::String::from(#name),
                                             ::type_description::TypeKind::Wrapped(
-                                                std::boxed::Box::new(<#ty as ::type_description::AsTypeDescription>::as_type_description())
+                                                std::boxed::Box::new(#desc)
                                             ),
                                             None,
                                         ))
                                     )
-                                )
+                                ) #label
                             }
                         }
-                        TypeVariantKind::Struct(ident, fields) => {
-                            let fields = fields.iter().map(|field| {
-
-                                match field {
-                                    TypeField::Simple { ident, ty, docs, optional } =>  {
-                                        let ident = ident.to_string();
-                                        let docs = lit_strings_to_string_quoted(docs);
-                                        quote! {
-                                            ::type_description::StructField::new(
-                                                #ident,
-                                                #docs,
-                                                <#ty as ::type_description::AsTypeDescription>::as_type_description(),
-                                                #optional,
-                                            )
-                                        }
-                                    }
-                                    TypeField::Flatten { ty: _ } => {
-                                        quote! {}
-                                    }
-                                }
-
-                            });
+                        TypeVariantKind::Struct(_, fields) => {
+                            let fields = quote_struct_fields(fields);
 
-                            let ident = ident.to_string();
                             quote! {
                                 ::type_description::EnumVariant::new(
-                                    #ident,
+                                    #name,
                                     #docs,
                                     ::type_description::EnumVariantRepresentation::Wrapped(
                                         std::boxed::Box::new(::type_description::TypeDescription::new(
-                                            ::std::string::String::from(#ident),
-                                            ::type_description::TypeKind::Struct({
-                                                let mut fields = vec![];
-                                                #(
-                                                    fields.push(#fields);
-                                                 )*
-                                                fields
-                                            }),
+                                            ::std::string::String::from(#name),
+                                            ::type_description::TypeKind::Struct(#fields),
                                             None
                                         ))
                                     )
-                                )
+                                ) #label
                             }
                         }
-                        TypeVariantKind::String(ident) => {
-                            let ident = ident.to_string();
+                        TypeVariantKind::String(_) => {
                             quote!{
                                 ::type_description::EnumVariant::new(
-                                    #ident,
+                                    #name,
                                     #docs,
                                     ::type_description::EnumVariantRepresentation::String(
-                                        ::std::borrow::Cow::Borrowed(#ident)
+                                        ::std::borrow::Cow::Borrowed(#name)
                                     )
-                                )
+                                ) #label
                             }
                         }
                     }
@@ -314,10 +665,11 @@ impl<'q> ToTokens for TypeQuote<'q> {
                 quote! {
                     ::type_description::TypeDescription::new(
                         ::std::string::String::from(#ident_name),
-                        ::type_description::TypeKind::Enum(
-                            #kind,
-                            vec![#(#variants),*]
-                        ),
+                        ::type_description::TypeKind::Enum {
+                            tagging: #kind,
+                            variants: vec![#(#variants),*],
+                            non_exhaustive: #non_exhaustive,
+                        },
                         #outer_docs
                     )
                 }
@@ -326,6 +678,332 @@ impl<'q> ToTokens for TypeQuote<'q> {
     }
 }
 
+/// Get `T` if the type is written as `Option<T>`
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(syn::TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether the type is a `PhantomData` marker, which has no value worth describing
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(syn::TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+fn named_fields_to_type_fields(
+    fields: &syn::FieldsNamed,
+    use_serde: bool,
+    rename_all: Option<RenameRule>,
+) -> Vec<TypeField<'_>> {
+    fields
+        .named
+        .iter()
+        .filter(|f| !is_phantom_data(&f.ty))
+        .map(|f| {
+            let name = f.ident.as_ref().unwrap().to_string();
+            // An `Option` can be left out, so it is described as an optional field of its inner type
+            let inner_ty = option_inner_type(&f.ty);
+            (
+                f,
+                TypeField::Simple {
+                    name: match rename_all {
+                        Some(rule) => rule.apply_to_field(&name),
+                        None => name,
+                    },
+                    label: None,
+                    ty: inner_ty.unwrap_or(&f.ty),
+                    kind: None,
+                    constraints: vec![],
+                    examples: vec![],
+                    docs: extract_docs_from_attributes(f.attrs.iter()),
+                    optional: inner_ty.is_some(),
+                },
+            )
+        })
+        .flat_map(|(field, mut type_field)| {
+            let description_field_attrs = extract_description_field_attributes(field.attrs.iter());
+            let mut label = None;
+
+            for attr in description_field_attrs {
+                match attr {
+                    DescriptionFieldAttribute::Skip => return None,
+                    DescriptionFieldAttribute::Rename(lit_str) => label = Some(lit_str.value()),
+                    DescriptionFieldAttribute::Kind(lit_str) => {
+                        if let TypeField::Simple { kind, .. } = &mut type_field {
+                            *kind = Some(builtin_kind_to_type(&lit_str));
+                        }
+                    }
+                    DescriptionFieldAttribute::Constraint(constraint) => {
+                        if let TypeField::Simple { constraints, .. } = &mut type_field {
+                            constraints.push(constraint);
+                        }
+                    }
+                    DescriptionFieldAttribute::Example(lit_str) => {
+                        if let TypeField::Simple { examples, .. } = &mut type_field {
+                            examples.push(lit_str);
+                        }
+                    }
+                    DescriptionFieldAttribute::VariantRepr(_) => {
+                        abort!(
+                            field,
+                            "Only enum variants can have their representation overridden"
+                        )
+                    }
+                }
+            }
+
+            if use_serde {
+                let serde_attrs = extract_serde_attributes(field.attrs.iter());
+
+                if let Some(serde_attrs) = serde_attrs {
+                    if serde_attrs.iter().any(|s| s == &SerdeAttribute::Flatten) {
+                        if let TypeField::Simple {
+                            name,
+                            docs,
+                            optional,
+                            ..
+                        } = type_field
+                        {
+                            type_field = TypeField::Flatten {
+                                name,
+                                label: None,
+                                ty: &field.ty,
+                                docs,
+                                optional: optional
+                                    || serde_attrs.contains(&SerdeAttribute::HasDefault),
+                            };
+                        }
+                    }
+
+                    if let TypeField::Simple {
+                        name,
+                        label: _,
+                        ty: _,
+                        kind: _,
+                        constraints: _,
+                        examples: _,
+                        docs: _,
+                        optional,
+                    } = &mut type_field
+                    {
+                        for attr in serde_attrs {
+                            match attr {
+                                SerdeAttribute::Rename(litstr) => {
+                                    *name = litstr.value();
+                                }
+                                SerdeAttribute::HasDefault => {
+                                    *optional = true;
+                                }
+                                SerdeAttribute::Skip => {
+                                    return None;
+                                }
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+            }
+
+            let (TypeField::Simple {
+                label: field_label, ..
+            }
+            | TypeField::Flatten {
+                label: field_label, ..
+            }) = &mut type_field;
+            *field_label = label;
+
+            Some(type_field)
+        })
+        .collect()
+}
+
+/// Find the type of the only field of a `#[serde(transparent)]` struct
+///
+/// Skipped fields and `PhantomData` markers do not count, as serde leaves them out as well.
+fn transparent_field_type<'q>(ident: &Ident, fields: &'q syn::FieldsNamed) -> &'q Type {
+    let mut fields = fields.named.iter().filter(|field| {
+        let skipped = extract_description_field_attributes(field.attrs.iter())
+            .iter()
+            .any(|attr| matches!(attr, DescriptionFieldAttribute::Skip))
+            || extract_serde_attributes(field.attrs.iter())
+                .unwrap_or_default()
+                .contains(&SerdeAttribute::Skip);
+        !skipped && !is_phantom_data(&field.ty)
+    });
+
+    match (fields.next(), fields.next()) {
+        (Some(field), None) => &field.ty,
+        _ => abort!(
+            ident,
+            "Transparent structs need exactly one field that is not skipped"
+        ),
+    }
+}
+
+fn is_as_type_description_bound(bound: &syn::TypeParamBound) -> bool {
+    match bound {
+        syn::TypeParamBound::Trait(bound) => bound
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "AsTypeDescription"),
+        syn::TypeParamBound::Lifetime(_) => false,
+    }
+}
+
+/// The types of the fields whose descriptions are part of the description
+///
+/// Skipped fields and `PhantomData` markers are left out already, and fields described as a
+/// built-in kind do not need the description of their type.
+fn described_types<'q>(kind: &TypeQuoteKind<'q>) -> Vec<&'q Type> {
+    fn field_type<'q>(field: &TypeField<'q>) -> Option<&'q Type> {
+        match field {
+            TypeField::Simple { kind: Some(_), .. } => None,
+            TypeField::Simple { ty, .. } | TypeField::Flatten { ty, .. } => Some(ty),
+        }
+    }
+
+    match kind {
+        TypeQuoteKind::Wrapped(ty) => vec![ty],
+        TypeQuoteKind::Tuple(tys) => tys.clone(),
+        TypeQuoteKind::Unit => vec![],
+        TypeQuoteKind::Struct(fields) => fields.iter().filter_map(field_type).collect(),
+        TypeQuoteKind::Enum(_, variants, _) => variants
+            .iter()
+            .flat_map(|variant| match &variant.kind {
+                TypeVariantKind::String(_) => vec![],
+                TypeVariantKind::Wrapped(_, field) => field_type(field).into_iter().collect(),
+                TypeVariantKind::Struct(_, fields) => {
+                    fields.iter().filter_map(field_type).collect()
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Whether the identifier appears anywhere in the tokens
+fn mentions_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(other) => other == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Require the type parameters that appear in the described types to implement
+/// `AsTypeDescription`, unless they already do
+///
+/// Like serde, parameters that are only used by skipped fields or `PhantomData` markers are left
+/// without a bound.
+fn add_trait_bounds(mut generics: syn::Generics, described: &[&Type]) -> syn::Generics {
+    let bounded_in_where_clause = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates)
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(predicate)
+                if predicate.bounds.iter().any(is_as_type_description_bound) =>
+            {
+                Some(predicate.bounded_ty.clone())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for param in generics.type_params_mut() {
+        let ident = &param.ident;
+        let already_bounded = param.bounds.iter().any(is_as_type_description_bound)
+            || bounded_in_where_clause
+                .iter()
+                .any(|ty| *ty == syn::parse_quote!(#ident));
+
+        let described = described
+            .iter()
+            .any(|ty| mentions_ident(ty.to_token_stream(), ident));
+
+        if described && !already_bounded {
+            param
+                .bounds
+                .push(syn::parse_quote!(::type_description::AsTypeDescription));
+        }
+    }
+
+    generics
+}
+
+/// Derive `AsTypeDescription` for a struct or an enum
+///
+/// The doc comments of the type, its fields and its variants become their documentation. Further
+/// details are given with `#[description(...)]` attributes.
+///
+/// Type parameters that appear in the type of a described field have to implement
+/// `AsTypeDescription` as well. Parameters only used by skipped fields or `PhantomData` markers
+/// do not.
+///
+/// # Container attributes
+///
+/// - `use_serde`: follow the `#[serde(...)]` attributes of the type, its fields and its variants,
+///   i.e. `rename`, `rename_all`, `tag`, `content`, `untagged`, `transparent`, `default`,
+///   `flatten` and `skip`
+/// - `name = "..."`: name the type differently than its identifier
+/// - `tag = "..."`: the enum is internally tagged with the given field
+/// - `tag = "...", content = "..."`: the enum is adjacently tagged with the given fields
+/// - `untagged`: the enum is untagged
+///
+/// Enums need one of `tag`, `untagged` or `use_serde`. With `use_serde` but no serde tagging, an
+/// enum is externally tagged, like serde does it.
+///
+/// # Field and variant attributes
+///
+/// - `skip`: leave the field or variant out
+/// - `rename = "..."`: document the field or variant with a different name, see below
+/// - `kind = "..."`: describe a field as one of the built-in kinds `"Bool"`, `"Integer"`,
+///   `"Float"`, `"String"`, `"Char"`, `"Bytes"` or `"Any"` instead of by its type
+/// - `min = ...`, `max = ...`: the bounds of a numeric field
+/// - `min_len = ...`, `max_len = ...`: the bounds of the length of a string or collection field
+/// - `pattern = "..."`: a regular expression that string values of the field have to match, which
+///   is checked when compiling with the `regex` feature
+/// - `example = "..."`: an example value of the field, written the way it appears in a
+///   configuration, which can be given several times
+/// - `variant_repr = "string"` or `variant_repr = "wrapped"`: describe a variant as a bare string
+///   or as a value regardless of its fields, where only unit variants can be strings
+///
+/// # Names of fields and variants
+///
+/// Fields and variants have a name they are serialized with, `StructField::name` and
+/// `EnumVariant::name`, and a name they are documented with, `StructField::label` and
+/// `EnumVariant::label`.
+///
+/// The serialized name is, in this order of precedence, the name given with `#[serde(rename)]`,
+/// the identifier converted with `#[serde(rename_all)]`, or the identifier itself. The serde
+/// attributes only count with `use_serde`. `#[description(rename)]` never changes the serialized
+/// name, so serde's renames always win for the keys of serialized values.
+///
+/// The label is the name given with `#[description(rename)]`, or the serialized name otherwise.
+///
+/// The renderers that document a type for people, i.e. the markdown, terminal, plain and HTML
+/// renderers, show the label. Everything that works with serialized values uses the serialized
+/// name, i.e. the JSON Schema, GraphQL, Rust, proto, signature and table renderers, the examples
+/// and the validation of values.
 #[proc_macro_derive(TypeDescription, attributes(description))]
 #[proc_macro_error]
 pub fn derive_type_description(input: TS) -> TS {
@@ -345,103 +1023,62 @@ pub fn derive_type_description(input: TS) -> TS {
         .any(|attr| match attr.parse_meta() {
             Err(_) => false,
             Ok(meta) => match meta {
-                syn::Meta::List(kind) => {
-                    if kind.nested.len() != 1 {
-                        return false;
-                    }
-
-                    match kind.nested.first() {
-                        Some(NestedMeta::Meta(Meta::Path(path))) => path.is_ident("use_serde"),
-                        _ => false,
-                    }
-                }
+                syn::Meta::List(kind) => kind.nested.iter().any(|nested| {
+                    matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("use_serde"))
+                }),
                 _ => false,
             },
         });
 
+    let rename_all = if use_serde {
+        extract_serde_rename_all(input.attrs.iter())
+    } else {
+        None
+    };
+
     let type_desc_kind: TypeQuoteKind = match &input.data {
         syn::Data::Struct(data) => match &data.fields {
-            syn::Fields::Named(fields) => TypeQuoteKind::Struct(
-                fields
-                    .named
-                    .iter()
-                    .map(|f| {
-                        (
-                            f,
-                            TypeField::Simple {
-                                ident: f.ident.as_ref().cloned().unwrap(),
-                                ty: &f.ty,
-                                docs: extract_docs_from_attributes(f.attrs.iter()),
-                                optional: false,
-                            },
-                        )
-                    })
-                    .flat_map(|(field, mut type_field)| {
-                        if use_serde {
-                            let serde_field_attrs =
-                                extra_serde_field_attributes(field.attrs.iter());
-
-                            if let Some(serde_field_attrs) = serde_field_attrs {
-                                if serde_field_attrs
-                                    .iter()
-                                    .any(|s| s == &SerdeFieldAttribute::Flatten)
-                                {
-                                    type_field = TypeField::Flatten { ty: &field.ty };
-                                }
+            // A transparent struct is (de)serialized as its only field
+            syn::Fields::Named(fields)
+                if use_serde
+                    && extract_serde_attributes(input.attrs.iter())
+                        .unwrap_or_default()
+                        .contains(&SerdeAttribute::Transparent) =>
+            {
+                TypeQuoteKind::Wrapped(transparent_field_type(ident, fields))
+            }
+            syn::Fields::Named(fields) => {
+                let mut fields = named_fields_to_type_fields(fields, use_serde, rename_all);
 
-                                if let TypeField::Simple {
-                                    ident,
-                                    ty: _,
-                                    docs: _,
-                                    optional,
-                                } = &mut type_field
-                                {
-                                    for attr in serde_field_attrs {
-                                        match attr {
-                                            SerdeFieldAttribute::Rename(litstr) => {
-                                                *ident = Ident::new(&litstr.value(), litstr.span());
-                                            }
-                                            SerdeFieldAttribute::HasDefault => {
-                                                *optional = true;
-                                            }
-                                            SerdeFieldAttribute::Skip => {
-                                                return None;
-                                            }
-                                            _ => (),
-                                        }
-                                    }
-                                }
-                            }
-                            Some(type_field)
-                        } else {
-                            Some(type_field)
-                        }
-                    })
-                    .collect(),
-            ),
-            syn::Fields::Unnamed(fields) => {
-                if fields.unnamed.len() != 1 {
-                    abort!(
-                        fields,
-                        "Tuple structs should only contain a single variant."
-                    )
+                // With a default for the whole struct, every field can be left out
+                if use_serde
+                    && extract_serde_attributes(input.attrs.iter())
+                        .unwrap_or_default()
+                        .contains(&SerdeAttribute::HasDefault)
+                {
+                    for field in &mut fields {
+                        let (TypeField::Simple { optional, .. }
+                        | TypeField::Flatten { optional, .. }) = field;
+                        *optional = true;
+                    }
                 }
+
+                TypeQuoteKind::Struct(fields)
+            }
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
                 TypeQuoteKind::Wrapped(&fields.unnamed.first().unwrap().ty)
             }
-            syn::Fields::Unit => abort!(
-                ident,
-                "Unit structs are not supported as they cannot be represented"
-            ),
+            syn::Fields::Unnamed(fields) => {
+                TypeQuoteKind::Tuple(fields.unnamed.iter().map(|field| &field.ty).collect())
+            }
+            syn::Fields::Unit => TypeQuoteKind::Unit,
         },
         syn::Data::Enum(data) => {
             let enum_kind: TypeEnumKind = {
-                let error_no_kind = || abort!(ident, "Enums need to specify what kind of tagging they use"; help = "Use #[description(untagged)] for untagged enums, and #[description(tag = \"type\")] for internally tagged variants. Other kinds are not supported.");
-
-                if desc_container_attributes.is_empty() {
-                    error_no_kind()
-                }
+                let error_no_kind = || abort!(ident, "Enums need to specify what kind of tagging they use"; help = "Use #[description(untagged)] for untagged enums, and #[description(tag = \"type\")] for internally tagged variants, #[description(tag = \"type\", content = \"data\")] for adjacently tagged variants, or #[description(use_serde)] to use the serde attributes. Other kinds are not supported.");
 
                 let mut found_enum_kind = None;
+                let mut found_content = None;
 
                 for potential_kind in &desc_container_attributes {
                     match potential_kind
@@ -449,28 +1086,26 @@ pub fn derive_type_description(input: TS) -> TS {
                         .expect_or_abort("Could not parse #[description] meta attribute.")
                     {
                         syn::Meta::List(kind) => {
-                            if kind.nested.len() != 1 {
-                                continue;
-                            }
-
-                            match kind.nested.first() {
-                                Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
-                                    path,
-                                    lit: Lit::Str(lit_str),
-                                    ..
-                                }))) => {
-                                    if path.is_ident("tag") {
-                                        found_enum_kind =
-                                            Some(TypeEnumKind::Tagged(lit_str.clone()));
+                            for nested in &kind.nested {
+                                match nested {
+                                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                        path,
+                                        lit: Lit::Str(lit_str),
+                                        ..
+                                    })) => {
+                                        if path.is_ident("tag") {
+                                            found_enum_kind =
+                                                Some(TypeEnumKind::Tagged(lit_str.clone()));
+                                        } else if path.is_ident("content") {
+                                            found_content = Some(lit_str.clone());
+                                        }
                                     }
-                                }
-                                Some(NestedMeta::Meta(Meta::Path(path))) => {
-                                    if path.is_ident("untagged") {
-                                        found_enum_kind = Some(TypeEnumKind::Untagged);
+                                    NestedMeta::Meta(Meta::Path(path)) => {
+                                        if path.is_ident("untagged") {
+                                            found_enum_kind = Some(TypeEnumKind::Untagged);
+                                        }
                                     }
-                                }
-                                _ => {
-                                    continue;
+                                    _ => continue,
                                 }
                             }
                         }
@@ -478,6 +1113,38 @@ pub fn derive_type_description(input: TS) -> TS {
                     }
                 }
 
+                if found_enum_kind.is_none() && use_serde {
+                    let serde_attrs =
+                        extract_serde_attributes(input.attrs.iter()).unwrap_or_default();
+                    found_content = serde_attrs.iter().find_map(|attr| match attr {
+                        SerdeAttribute::Content(content) => Some(content.clone()),
+                        _ => None,
+                    });
+                    found_enum_kind = Some(
+                        serde_attrs
+                            .into_iter()
+                            .find_map(|attr| match attr {
+                                SerdeAttribute::Tag(tag) => Some(TypeEnumKind::Tagged(tag)),
+                                SerdeAttribute::Untagged => Some(TypeEnumKind::Untagged),
+                                _ => None,
+                            })
+                            .unwrap_or(TypeEnumKind::External),
+                    );
+                }
+
+                // A content field next to the tag makes the tagging adjacent
+                if let Some(content) = found_content {
+                    match found_enum_kind {
+                        Some(TypeEnumKind::Tagged(tag)) => {
+                            found_enum_kind = Some(TypeEnumKind::Adjacent { tag, content })
+                        }
+                        _ => abort!(
+                            content,
+                            "A content field can only be used together with a tag"
+                        ),
+                    }
+                }
+
                 if found_enum_kind.is_none() {
                     error_no_kind()
                 }
@@ -488,20 +1155,57 @@ pub fn derive_type_description(input: TS) -> TS {
             let variants = data
                 .variants
                 .iter()
-                .map(|var| {
+                .filter_map(|var| {
+                    let mut name = match rename_all {
+                        Some(rule) => rule.apply_to_variant(&var.ident.to_string()),
+                        None => var.ident.to_string(),
+                    };
+                    let mut fields_rename_all = None;
+
+                    if use_serde {
+                        for attr in extract_serde_attributes(var.attrs.iter()).unwrap_or_default() {
+                            match attr {
+                                SerdeAttribute::Rename(litstr) => {
+                                    name = litstr.value();
+                                }
+                                SerdeAttribute::RenameAll(litstr) => {
+                                    fields_rename_all = Some(RenameRule::from_lit(&litstr));
+                                }
+                                SerdeAttribute::Skip => {
+                                    return None;
+                                }
+                                _ => (),
+                            }
+                        }
+                    }
+
+                    let mut label = None;
+                    let mut variant_repr = None;
+                    for attr in extract_description_field_attributes(var.attrs.iter()) {
+                        match attr {
+                            DescriptionFieldAttribute::Skip => return None,
+                            DescriptionFieldAttribute::VariantRepr(repr) => {
+                                variant_repr = Some(repr)
+                            }
+                            DescriptionFieldAttribute::Rename(lit_str) => {
+                                label = Some(lit_str.value())
+                            }
+                            DescriptionFieldAttribute::Kind(lit_str) => {
+                                abort!(lit_str, "Enum variants cannot have their kind overridden")
+                            }
+                            DescriptionFieldAttribute::Constraint(_) => {
+                                abort!(var, "Enum variants cannot have constraints")
+                            }
+                            DescriptionFieldAttribute::Example(lit_str) => {
+                                abort!(lit_str, "Enum variants cannot have examples")
+                            }
+                        }
+                    }
+
                     let kind = match &var.fields {
                         syn::Fields::Named(fields) => TypeVariantKind::Struct(
                             &var.ident,
-                            fields
-                                .named
-                                .iter()
-                                .map(|f| TypeField::Simple {
-                                    ident: f.ident.as_ref().cloned().unwrap(),
-                                    ty: &f.ty,
-                                    docs: extract_docs_from_attributes(f.attrs.iter()),
-                                    optional: false,
-                                })
-                                .collect(),
+                            named_fields_to_type_fields(fields, use_serde, fields_rename_all),
                         ),
                         syn::Fields::Unnamed(fields) => {
                             if fields.unnamed.len() != 1 {
@@ -513,8 +1217,12 @@ pub fn derive_type_description(input: TS) -> TS {
                             TypeVariantKind::Wrapped(
                                 &var.ident,
                                 TypeField::Simple {
-                                    ident: var.ident.clone(),
+                                    name: name.clone(),
+                                    label: None,
                                     ty: &fields.unnamed.first().unwrap().ty,
+                                    kind: None,
+                                    constraints: vec![],
+                                    examples: vec![],
                                     docs: extract_docs_from_attributes(var.attrs.iter()),
                                     optional: false,
                                 },
@@ -522,15 +1230,38 @@ pub fn derive_type_description(input: TS) -> TS {
                         }
                         syn::Fields::Unit => TypeVariantKind::String(&var.ident),
                     };
+                    let kind = match (variant_repr, kind) {
+                        (Some(VariantRepr::String), TypeVariantKind::String(ident)) => {
+                            TypeVariantKind::String(ident)
+                        }
+                        // serde writes the fields of a variant, so it is never a plain string
+                        (Some(VariantRepr::String), _) => abort!(
+                            var.fields,
+                            "Only unit variants can be represented as a string";
+                            help = "Remove the fields of the variant, or use #[description(variant_repr = \"wrapped\")]"
+                        ),
+                        // A unit variant that is wrapped anyway is an object without fields
+                        (Some(VariantRepr::Wrapped), TypeVariantKind::String(ident)) => {
+                            TypeVariantKind::Struct(ident, vec![])
+                        }
+                        (_, kind) => kind,
+                    };
                     let docs = extract_docs_from_attributes(var.attrs.iter());
-                    Some(TypeVariant { kind, docs })
+                    Some(TypeVariant {
+                        name,
+                        label,
+                        kind,
+                        docs,
+                    })
                 })
-                .collect::<Option<_>>();
+                .collect::<Vec<_>>();
 
-            TypeQuoteKind::Enum(
-                enum_kind,
-                variants.expect_or_abort("Enum contains invalid variants"),
-            )
+            let non_exhaustive = input
+                .attrs
+                .iter()
+                .any(|attr| attr.path.is_ident("non_exhaustive"));
+
+            TypeQuoteKind::Enum(enum_kind, variants, non_exhaustive)
         }
         syn::Data::Union(_) => {
             abort!(
@@ -541,17 +1272,38 @@ pub fn derive_type_description(input: TS) -> TS {
     };
 
     let docs = extract_docs_from_attributes(input.attrs.iter());
+    let described = described_types(&type_desc_kind);
+
+    // A display name given with #[description(name = "...")] replaces the identifier
+    let name = desc_container_attributes
+        .iter()
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit_str),
+                ..
+            })) if path.is_ident("name") => Some(lit_str.value()),
+            _ => None,
+        })
+        .unwrap_or_else(|| ident.to_string());
 
     let type_desc = TypeQuote {
+        name: name.clone(),
         kind: type_desc_kind,
         docs,
-        ident,
     };
 
+    let generics = add_trait_bounds(input.generics.clone(), &described);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let expanded = quote! {
-        impl ::type_description::AsTypeDescription for #ident {
+        impl #impl_generics ::type_description::AsTypeDescription for #ident #ty_generics #where_clause {
             fn as_type_description() -> ::type_description::TypeDescription {
-                #type_desc
+                ::type_description::describe_recursive::<Self>(#name, || #type_desc)
             }
         }
     };